@@ -15,7 +15,7 @@ fn main() {
     let mut inter_a_b = (&a & &b).unwrap();
     println!("area A={}, area B={}; A&B {}", a.area(), b.area(), inter_a_b.area());
     //area A=22500, area B=15000; A&B 5000
-    println!("inter_a_b intersects pt = {}", inter_a_b.intersects_point(&pt));
+    println!("inter_a_b intersects pt = {}", inter_a_b.intersects_point(pt));
 
     //union
     let union_a_b = &a | &b;
@@ -31,7 +31,7 @@ fn main() {
     println!("{}", inter_a_b); //POLYGON ((270 225,270 375,380 375,380 225,270 225))
 
     //contains
-    println!("inter_a_b intersects pt = {}", inter_a_b.intersects_point(&pt));
+    println!("inter_a_b intersects pt = {}", inter_a_b.intersects_point(pt));
     println!("inter_a_b intersects pt = {}", inter_a_b.intersects_xy(pt[0], pt[1]));
     println!("inter_a_b intersects pt = {}", inter_a_b.contains(&MBR::new_from_pt(pt)));
     println!("inter_a_b intersects pt = {}", inter_a_b.contains_xy(pt[0], pt[1]));
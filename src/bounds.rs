@@ -0,0 +1,72 @@
+use crate::{BBox, BBoxObject, Boxes, IndexedMBR, MBR};
+
+///Bounds of a geometry computed on demand, a companion to `BBox` for types
+///that do not store an MBR. Points are `[f64; 2]`, polylines `[[f64; 2]]`
+///and polygons with holes a list of rings `[Vec<[f64; 2]>]`; any list of bounded
+///geometries is bounded.
+pub trait Bounds {
+    ///Bounding box, None if empty
+    fn bounds(&self) -> Option<MBR>;
+}
+
+///Bounds for MBR, None if empty
+impl Bounds for MBR {
+    fn bounds(&self) -> Option<MBR> {
+        if self.is_empty() { None } else { Some(*self) }
+    }
+}
+
+///Bounds for IndexedMBR
+impl Bounds for IndexedMBR {
+    fn bounds(&self) -> Option<MBR> {
+        self.mbr.bounds()
+    }
+}
+
+///Bounds for BBoxObject
+impl<T: BBox> Bounds for BBoxObject<T> {
+    fn bounds(&self) -> Option<MBR> {
+        self.bbox().bounds()
+    }
+}
+
+///Bounds for point
+impl Bounds for [f64; 2] {
+    fn bounds(&self) -> Option<MBR> {
+        Some(MBR::new_from_pt(*self))
+    }
+}
+
+///Bounds for a list of bounded geometries: polylines, rings of polygons, ...
+impl<T: Bounds> Bounds for [T] {
+    fn bounds(&self) -> Option<MBR> {
+        self.iter().filter_map(Bounds::bounds).fold(None, |acc, b| match acc {
+            Some(mut m) => {
+                m.expand_to_include(&b);
+                Some(m)
+            }
+            None => Some(b),
+        })
+    }
+}
+
+///Bounds for Vec of bounded geometries
+impl<T: Bounds> Bounds for Vec<T> {
+    fn bounds(&self) -> Option<MBR> {
+        self.as_slice().bounds()
+    }
+}
+
+///Bounds for reference
+impl<T: Bounds + ?Sized> Bounds for &T {
+    fn bounds(&self) -> Option<MBR> {
+        (**self).bounds()
+    }
+}
+
+///Bounds for Boxes
+impl Bounds for Boxes {
+    fn bounds(&self) -> Option<MBR> {
+        self.boxes.bounds()
+    }
+}
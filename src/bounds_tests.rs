@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_bounds() {
+    assert_eq!([1., 2.].bounds(), Some(MBR::new(1., 2., 1., 2.)));
+    assert_eq!(MBR::new(0., 0., 1., 1.).bounds(), Some(MBR::new(0., 0., 1., 1.)));
+
+    let line = vec![[0., 0.], [3., -1.], [2., 4.]];
+    assert_eq!(line.bounds(), Some(MBR::new(0., -1., 3., 4.)));
+    assert_eq!(line[..2].bounds(), Some(MBR::new(0., -1., 3., 0.)));
+    let empty: Vec<[f64; 2]> = vec![];
+    assert_eq!(empty.bounds(), None);
+
+    //polygon with a hole, multipolygon
+    let poly = vec![
+        vec![[0., 0.], [10., 0.], [10., 10.], [0., 10.], [0., 0.]],
+        vec![[2., 2.], [4., 2.], [4., 4.], [2., 2.]],
+    ];
+    assert_eq!(poly.bounds(), Some(MBR::new(0., 0., 10., 10.)));
+    let multi = vec![poly.clone(), vec![vec![[-5., 20.], [-4., 21.], [-5., 21.], [-5., 20.]]]];
+    assert_eq!(multi.bounds(), Some(MBR::new(-5., 0., 10., 21.)));
+    assert_eq!(Bounds::bounds(&&multi[..]), multi.bounds());
+
+    let data: Boxes = vec![[0, 0, 1, 1], [5, -2, 6, 0]].into();
+    assert_eq!(data.bounds(), Some(MBR::new(0., -2., 6., 1.)));
+    assert_eq!(data.extent(), data.bounds());
+
+    let indexed = IndexedMBR { mbr: MBR::new(1., 1., 2., 2.), index: 0 };
+    assert_eq!(indexed.bounds(), Some(indexed.mbr));
+    assert_eq!(BBoxObject(indexed.mbr).bounds(), Some(indexed.mbr));
+    let empty = IndexedMBR { mbr: MBR::new_empty(), index: 1 };
+    assert_eq!(empty.bounds(), None);
+    assert_eq!(BBoxObject(empty).bounds(), None);
+}
+
+#[test]
+fn test_bounded_geometries() {
+    let lines = vec![
+        vec![[0., 0.], [2., 2.]],
+        vec![],
+        vec![[5., 5.], [7., 4.], [6., 9.]],
+    ];
+    let data = Boxes::from_bounds(&lines);
+    assert_eq!(data.boxes, vec![MBR::new(0., 0., 2., 2.), MBR::new(5., 4., 7., 9.)]);
+    assert_eq!(data.extent(), lines.bounds());
+
+    let mut grid = GridHash::new(4.);
+    let mut tree = QuadTree::new(MBR::new(0., 0., 10., 10.), QuadTreeConfig::default());
+    for (id, line) in lines.iter().enumerate() {
+        assert_eq!(grid.insert_bounds(id, line), !line.is_empty());
+        assert_eq!(tree.insert_bounds(id, &line[..]), !line.is_empty());
+    }
+    assert_eq!(SpatialQuery::query_point(&grid, [6., 8.]), vec![2]);
+    assert_eq!(SpatialQuery::query(&tree, &MBR::new(1., 1., 5.5, 5.5)).len(), 2);
+}
@@ -0,0 +1,208 @@
+use crate::{Boxes, MBR};
+
+///Bits per mask word
+const LANES: usize = 64;
+
+///Boxes in columns (struct of arrays) for bulk filtering.
+///
+///Tests run over the minx, miny, maxx and maxy columns in chunks of 64 boxes without
+///branches, so the compiler can vectorize them; results are bitmasks with bit
+///`i % 64` of word `i / 64` set for box i, or index lists. Columns are filled only through
+///`push` and `From`, so they always have equal lengths.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoxColumns {
+    minx: Vec<f64>,
+    miny: Vec<f64>,
+    maxx: Vec<f64>,
+    maxy: Vec<f64>,
+}
+
+impl BoxColumns {
+    ///New empty columns
+    pub fn new() -> BoxColumns {
+        BoxColumns::default()
+    }
+
+    ///New empty columns with capacity for n boxes
+    pub fn with_capacity(n: usize) -> BoxColumns {
+        BoxColumns {
+            minx: Vec::with_capacity(n),
+            miny: Vec::with_capacity(n),
+            maxx: Vec::with_capacity(n),
+            maxy: Vec::with_capacity(n),
+        }
+    }
+
+    ///Number of boxes
+    pub fn len(&self) -> usize {
+        self.minx.len()
+    }
+
+    ///Checks if there are no boxes
+    pub fn is_empty(&self) -> bool {
+        self.minx.is_empty()
+    }
+
+    ///Column of minx values
+    pub fn minx(&self) -> &[f64] {
+        &self.minx
+    }
+
+    ///Column of miny values
+    pub fn miny(&self) -> &[f64] {
+        &self.miny
+    }
+
+    ///Column of maxx values
+    pub fn maxx(&self) -> &[f64] {
+        &self.maxx
+    }
+
+    ///Column of maxy values
+    pub fn maxy(&self) -> &[f64] {
+        &self.maxy
+    }
+
+    ///Appends box
+    pub fn push(&mut self, mbr: &MBR) {
+        self.minx.push(mbr.minx);
+        self.miny.push(mbr.miny);
+        self.maxx.push(mbr.maxx);
+        self.maxy.push(mbr.maxy);
+    }
+
+    ///Box at index i
+    pub fn get(&self, i: usize) -> Option<MBR> {
+        Some(MBR::new_raw(*self.minx.get(i)?, self.miny[i], self.maxx[i], self.maxy[i]))
+    }
+
+    ///Mask of boxes that intersect window
+    pub fn intersects_mask(&self, window: &MBR) -> Vec<u64> {
+        let w = *window;
+        self.mask(|x0, y0, x1, y1| x0 <= w.maxx && x1 >= w.minx && y0 <= w.maxy && y1 >= w.miny)
+    }
+
+    ///Mask of boxes that contain other, boundaries may touch
+    pub fn contains_mask(&self, other: &MBR) -> Vec<u64> {
+        let o = *other;
+        self.mask(|x0, y0, x1, y1| x0 <= o.minx && y0 <= o.miny && x1 >= o.maxx && y1 >= o.maxy)
+    }
+
+    ///Mask of boxes inside window, boundaries may touch
+    pub fn within_mask(&self, window: &MBR) -> Vec<u64> {
+        let w = *window;
+        self.mask(|x0, y0, x1, y1| x0 >= w.minx && y0 >= w.miny && x1 <= w.maxx && y1 <= w.maxy)
+    }
+
+    ///Mask of boxes that contain point
+    pub fn contains_point_mask(&self, pt: [f64; 2]) -> Vec<u64> {
+        let [x, y] = pt;
+        self.mask(|x0, y0, x1, y1| x0 <= x && x1 >= x && y0 <= y && y1 >= y)
+    }
+
+    ///Mask of boxes within distance of point
+    pub fn within_distance_mask(&self, pt: [f64; 2], distance: f64) -> Vec<u64> {
+        let [x, y] = pt;
+        let d2 = distance * distance;
+        self.mask(|x0, y0, x1, y1| {
+            let dx = (x0 - x).max(0.0).max(x - x1);
+            let dy = (y0 - y).max(0.0).max(y - y1);
+            dx * dx + dy * dy <= d2
+        })
+    }
+
+    ///Indices of boxes that intersect window
+    pub fn intersects(&self, window: &MBR) -> Vec<usize> {
+        mask_indices(&self.intersects_mask(window))
+    }
+
+    ///Indices of boxes that contain other
+    pub fn contains(&self, other: &MBR) -> Vec<usize> {
+        mask_indices(&self.contains_mask(other))
+    }
+
+    ///Indices of boxes inside window
+    pub fn within(&self, window: &MBR) -> Vec<usize> {
+        mask_indices(&self.within_mask(window))
+    }
+
+    ///Distance of each box to point, 0 inside
+    pub fn distance(&self, pt: [f64; 2]) -> Vec<f64> {
+        let [x, y] = pt;
+        let cols = (&self.minx, &self.miny, &self.maxx, &self.maxy);
+        cols.0
+            .iter()
+            .zip(cols.1.iter())
+            .zip(cols.2.iter().zip(cols.3.iter()))
+            .map(|((&x0, &y0), (&x1, &y1))| {
+                let dx = (x0 - x).max(0.0).max(x - x1);
+                let dy = (y0 - y).max(0.0).max(y - y1);
+                (dx * dx + dy * dy).sqrt()
+            })
+            .collect()
+    }
+
+    ///Bitmask of test over the columns, 64 boxes per word
+    #[inline]
+    fn mask<F>(&self, test: F) -> Vec<u64>
+        where
+            F: Fn(f64, f64, f64, f64) -> bool,
+    {
+        let n = self.len();
+        let mut words = Vec::with_capacity(n / LANES + 1);
+        let chunks = self
+            .minx
+            .chunks(LANES)
+            .zip(self.miny.chunks(LANES))
+            .zip(self.maxx.chunks(LANES).zip(self.maxy.chunks(LANES)));
+        for ((x0, y0), (x1, y1)) in chunks {
+            let mut word = 0u64;
+            for j in 0..x0.len() {
+                word |= (test(x0[j], y0[j], x1[j], y1[j]) as u64) << j;
+            }
+            words.push(word);
+        }
+        words
+    }
+}
+
+///Indices of set bits in mask, ascending
+pub fn mask_indices(mask: &[u64]) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(mask.iter().map(|w| w.count_ones() as usize).sum());
+    for (i, &word) in mask.iter().enumerate() {
+        let mut w = word;
+        while w != 0 {
+            indices.push(i * LANES + w.trailing_zeros() as usize);
+            w &= w - 1;
+        }
+    }
+    indices
+}
+
+impl From<&Boxes> for BoxColumns {
+    fn from(boxes: &Boxes) -> Self {
+        let mut cols = BoxColumns::with_capacity(boxes.boxes.len());
+        for mbr in &boxes.boxes {
+            cols.push(mbr);
+        }
+        cols
+    }
+}
+
+impl From<Boxes> for BoxColumns {
+    fn from(boxes: Boxes) -> Self {
+        BoxColumns::from(&boxes)
+    }
+}
+
+impl From<&BoxColumns> for Boxes {
+    fn from(cols: &BoxColumns) -> Self {
+        Boxes { boxes: (0..cols.len()).filter_map(|i| cols.get(i)).collect() }
+    }
+}
+
+impl From<BoxColumns> for Boxes {
+    fn from(cols: BoxColumns) -> Self {
+        Boxes::from(&cols)
+    }
+}
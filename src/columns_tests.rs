@@ -0,0 +1,52 @@
+use super::*;
+use crate::test_util::random_boxes;
+
+#[test]
+fn test_columns() {
+    let data = random_boxes(1000, 11, 20.);
+    let cols = BoxColumns::from(&data);
+    assert_eq!((cols.len(), cols.is_empty()), (1000, false));
+    assert_eq!(cols.get(7), Some(data[7]));
+    assert_eq!(cols.get(1000), None);
+    assert_eq!(Boxes::from(&cols).boxes, data.boxes);
+    assert_eq!((cols.minx()[7], cols.miny()[7]), (data[7].minx, data[7].miny));
+    assert_eq!((cols.maxx()[7], cols.maxy()[7]), (data[7].maxx, data[7].maxy));
+
+    let window = MBR::new(20., 30., 45., 50.);
+    let filter = |f: &dyn Fn(&MBR) -> bool| -> Vec<usize> {
+        (0..data.boxes.len()).filter(|&i| f(&data[i])).collect()
+    };
+    assert_eq!(cols.intersects(&window), filter(&|b| b.intersects(&window)));
+    assert_eq!(cols.within(&window), filter(&|b| window.contains(b)));
+    let small = MBR::new(50., 50., 51., 52.);
+    assert_eq!(cols.contains(&small), filter(&|b| b.contains(&small)));
+    let inside = mask_indices(&cols.contains_point_mask([60., 10.]));
+    assert_eq!(inside, filter(&|b| b.contains_point([60., 10.])));
+
+    let pt = MBR::new_from_pt([33., 77.]);
+    let dists = cols.distance([33., 77.]);
+    for (&d, b) in dists.iter().zip(data.boxes.iter()) {
+        assert!((d - b.distance(&pt)).abs() < 1e-12);
+    }
+    let near = mask_indices(&cols.within_distance_mask([33., 77.], 5.));
+    assert_eq!(near, filter(&|b| b.distance(&pt) <= 5.));
+}
+
+#[test]
+fn test_columns_mask() {
+    let data: Boxes = vec![[0, 0, 1, 1], [5, 5, 6, 6], [0, 0, 10, 10]].into();
+    let mut cols = BoxColumns::from(data);
+    assert_eq!(cols.intersects_mask(&MBR::new(0.5, 0.5, 2., 2.)), vec![0b101]);
+    for _ in 0..64 {
+        cols.push(&MBR::new(5., 5., 6., 6.));
+    }
+    let mask = cols.intersects_mask(&MBR::new(5.5, 5.5, 5.5, 5.5));
+    assert_eq!(mask, vec![u64::MAX - 1, 0b111]);
+    assert_eq!(mask_indices(&mask).len(), 66);
+    assert_eq!(Boxes::from(cols).boxes.len(), 67);
+
+    let empty = BoxColumns::new();
+    assert!(empty.is_empty());
+    assert!(empty.intersects_mask(&MBR::new(0., 0., 1., 1.)).is_empty());
+    assert!(mask_indices(&[0, 0]).is_empty());
+}
@@ -0,0 +1,40 @@
+use super::*;
+use euclid::default::{Box2D, Point2D, Rect, Size2D, Vector2D};
+
+#[test]
+fn test_euclid() {
+    let mbr = MBR::new(1., 2., 4., 6.);
+    let b: Box2D<f64> = mbr.into();
+    assert_eq!((b.min, b.max), (Point2D::new(1., 2.), Point2D::new(4., 6.)));
+    assert_eq!(MBR::from(b), mbr);
+
+    let r: Rect<f64> = mbr.into();
+    assert_eq!((r.origin, r.size), (Point2D::new(1., 2.), Size2D::new(3., 4.)));
+    assert_eq!(MBR::from(r), mbr);
+    let flipped = Rect::new(Point2D::new(4., 6.), Size2D::new(-3., -4.));
+    assert!(MBR::from(flipped).is_empty());
+    assert_eq!(flipped.bounds(), None);
+    let inverted = Box2D::new(Point2D::new(4., 6.), Point2D::new(1., 2.));
+    assert!(MBR::from(inverted).is_empty());
+    assert_eq!(inverted.bounds(), None);
+    let point = Box2D::new(Point2D::new(1., 2.), Point2D::new(1., 2.));
+    assert_eq!(point.bounds(), Some(MBR::new_from_pt([1., 2.])));
+
+    assert_eq!(MBR::from(Point2D::new(5., 6.)), MBR::new_from_pt([5., 6.]));
+    assert_eq!(MBR::from(Vector2D::new(5., 6.)), MBR::new_from_pt([5., 6.]));
+    assert!(mbr.contains_point(Point2D::new(2., 5.)));
+    assert!(!mbr.contains_point(Point2D::new(5., 5.)));
+    assert!(mbr.completely_contains_point(Point2D::new(2., 5.)));
+    assert!(mbr.intersects_point(Vector2D::new(4., 6.)));
+    assert!(mbr.contains_point_eps(Point2D::new(4.1, 6.), 0.2));
+    assert_eq!(mbr.closest_point(Point2D::new(0., 3.)), [1., 3.]);
+    assert_eq!(mbr.farthest_point(Point2D::new(0., 3.)), [4., 6.]);
+    assert_eq!(mbr.minmax_distance(Point2D::new(1., 2.)), mbr.minmax_distance([1., 2.]));
+    assert_eq!(mbr.distance_point_metric(Point2D::new(0., 2.), Metric::Manhattan), 1.);
+
+    let mut m = MBR::from(b);
+    m.expand_to_include_point(Point2D::new(0., 10.));
+    assert_eq!(m, MBR::new(0., 2., 4., 10.));
+    assert_eq!(vec![Point2D::new(0., 10.), Point2D::new(4., 2.)].bounds(), Some(m));
+    assert_eq!(r.bounds(), Some(mbr));
+}
@@ -0,0 +1,59 @@
+use crate::{Bounds, MBR};
+use euclid::{Box2D, Point2D, Rect, Size2D, Vector2D};
+
+impl<U> From<Point2D<f64, U>> for MBR {
+    fn from(pt: Point2D<f64, U>) -> Self {
+        MBR::new_from_pt(pt.into())
+    }
+}
+
+impl<U> From<Vector2D<f64, U>> for MBR {
+    fn from(v: Vector2D<f64, U>) -> Self {
+        MBR::new_from_pt(v.into())
+    }
+}
+
+///Box with min greater than max is an empty MBR
+impl<U> From<Box2D<f64, U>> for MBR {
+    fn from(b: Box2D<f64, U>) -> Self {
+        MBR::new_raw(b.min.x, b.min.y, b.max.x, b.max.y)
+    }
+}
+
+impl<U> From<MBR> for Box2D<f64, U> {
+    fn from(mbr: MBR) -> Self {
+        Box2D::new(Point2D::new(mbr.minx, mbr.miny), Point2D::new(mbr.maxx, mbr.maxy))
+    }
+}
+
+///Rect with negative size is an empty MBR
+impl<U> From<Rect<f64, U>> for MBR {
+    fn from(r: Rect<f64, U>) -> Self {
+        let (o, s) = (r.origin, r.size);
+        MBR::new_raw(o.x, o.y, o.x + s.width, o.y + s.height)
+    }
+}
+
+impl<U> From<MBR> for Rect<f64, U> {
+    fn from(mbr: MBR) -> Self {
+        Rect::new(Point2D::new(mbr.minx, mbr.miny), Size2D::new(mbr.width(), mbr.height()))
+    }
+}
+
+impl<U> Bounds for Point2D<f64, U> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
+
+impl<U> Bounds for Box2D<f64, U> {
+    fn bounds(&self) -> Option<MBR> {
+        MBR::from(*self).bounds()
+    }
+}
+
+impl<U> Bounds for Rect<f64, U> {
+    fn bounds(&self) -> Option<MBR> {
+        MBR::from(*self).bounds()
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Boxes, MBR};
+use std::iter::{FromIterator, Sum};
+
+///Scale of the median absolute deviation to the standard deviation of a normal distribution
+const MAD_SCALE: f64 = 1.4826;
+
+///Outlier rule for trimmed extents, applied to each axis
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Trim {
+    ///Keep values between the lower and upper percentiles in [0, 100], e.g. (1, 99)
+    Percentile(f64, f64),
+    ///Keep values within k scaled median absolute deviations (about k standard deviations)
+    ///of the median
+    Mad(f64),
+}
+
+impl MBR {
+    ///MBR of points, None if there are none
+    pub fn from_points<I, P>(points: I) -> Option<MBR>
+        where
+            I: IntoIterator<Item = P>,
+            P: Into<[f64; 2]>,
+    {
+        let mut mbr = MBR::new_empty();
+        for pt in points {
+            mbr.expand_to_include_point(pt);
+        }
+        if mbr.is_empty() { None } else { Some(mbr) }
+    }
+
+    ///Extent of points excluding outliers on either axis, with the indices of the
+    ///excluded points in ascending order. Points with NaN coordinates are excluded.
+    ///None if every point is excluded. With `Trim::Mad` a median absolute deviation of 0,
+    ///as when most fixes repeat one position, keeps only points equal to the median.
+    pub fn trimmed_extent(points: &[[f64; 2]], trim: Trim) -> Option<(MBR, Vec<usize>)> {
+        let [(x0, x1), (y0, y1)] = [0, 1].map(|axis| {
+            let mut values: Vec<f64> =
+                points.iter().map(|p| p[axis]).filter(|v| !v.is_nan()).collect();
+            values.sort_by(f64::total_cmp);
+            trim_range(&values, trim)
+        });
+        let mut mbr = MBR::new_empty();
+        let mut excluded = vec![];
+        for (i, p) in points.iter().enumerate() {
+            if p[0] >= x0 && p[0] <= x1 && p[1] >= y0 && p[1] <= y1 {
+                mbr.expand_to_include_point(*p);
+            } else {
+                excluded.push(i);
+            }
+        }
+        if mbr.is_empty() { None } else { Some((mbr, excluded)) }
+    }
+
+    ///MBR of points in parallel, None if there are none
+    #[cfg(feature = "rayon")]
+    pub fn par_from_points(points: &[[f64; 2]]) -> Option<MBR> {
+        use rayon::prelude::*;
+        let mbr = points
+            .par_iter()
+            .fold(MBR::new_empty, |mut mbr, pt| {
+                mbr.expand_to_include_point(*pt);
+                mbr
+            })
+            .reduce(MBR::new_empty, |mut a, b| {
+                a.expand_to_include(&b);
+                a
+            });
+        if mbr.is_empty() { None } else { Some(mbr) }
+    }
+}
+
+impl Boxes {
+    ///Extent of boxes in parallel, None if empty
+    #[cfg(feature = "rayon")]
+    pub fn par_extent(&self) -> Option<MBR> {
+        use rayon::prelude::*;
+        let mbr = self
+            .boxes
+            .par_iter()
+            .fold(MBR::new_empty, |mut a, b| {
+                a.expand_to_include(b);
+                a
+            })
+            .reduce(MBR::new_empty, |mut a, b| {
+                a.expand_to_include(&b);
+                a
+            });
+        if mbr.is_empty() { None } else { Some(mbr) }
+    }
+}
+
+///MBR of points, empty if there are none
+impl FromIterator<[f64; 2]> for MBR {
+    fn from_iter<I: IntoIterator<Item = [f64; 2]>>(iter: I) -> Self {
+        let mut mbr = MBR::new_empty();
+        mbr.extend(iter);
+        mbr
+    }
+}
+
+///MBR of boxes, empty if there are none
+impl FromIterator<MBR> for MBR {
+    fn from_iter<I: IntoIterator<Item = MBR>>(iter: I) -> Self {
+        let mut mbr = MBR::new_empty();
+        mbr.extend(iter);
+        mbr
+    }
+}
+
+///Expands to include points
+impl Extend<[f64; 2]> for MBR {
+    fn extend<I: IntoIterator<Item = [f64; 2]>>(&mut self, iter: I) {
+        for pt in iter {
+            self.expand_to_include_point(pt);
+        }
+    }
+}
+
+///Expands to include boxes
+impl Extend<MBR> for MBR {
+    fn extend<I: IntoIterator<Item = MBR>>(&mut self, iter: I) {
+        for mbr in iter {
+            self.expand_to_include(&mbr);
+        }
+    }
+}
+
+///Union of boxes, empty if there are none
+impl Sum for MBR {
+    fn sum<I: Iterator<Item = MBR>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+///Union of boxes, empty if there are none
+impl<'a> Sum<&'a MBR> for MBR {
+    fn sum<I: Iterator<Item = &'a MBR>>(iter: I) -> Self {
+        iter.copied().collect()
+    }
+}
+
+///Range of sorted values kept by trim, empty (NaN) if there are no values
+fn trim_range(sorted: &[f64], trim: Trim) -> (f64, f64) {
+    if sorted.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    match trim {
+        Trim::Percentile(lo, hi) => {
+            let (lo, hi) = (lo.min(hi).clamp(0.0, 100.0), lo.max(hi).clamp(0.0, 100.0));
+            (percentile(sorted, lo), percentile(sorted, hi))
+        }
+        Trim::Mad(k) => {
+            let median = percentile(sorted, 50.0);
+            let mut dev: Vec<f64> = sorted.iter().map(|v| (v - median).abs()).collect();
+            dev.sort_by(f64::total_cmp);
+            let r = k.abs() * MAD_SCALE * percentile(&dev, 50.0);
+            (median - r, median + r)
+        }
+    }
+}
+
+///Percentile p in [0, 100] of sorted values, interpolated linearly between ranks
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (i, f) = (rank.floor() as usize, rank.fract());
+    match sorted.get(i + 1) {
+        Some(next) if f > 0.0 => sorted[i] + (next - sorted[i]) * f,
+        _ => sorted[i],
+    }
+}
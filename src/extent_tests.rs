@@ -0,0 +1,83 @@
+use super::*;
+
+#[test]
+fn test_empty() {
+    let empty = MBR::new_empty();
+    assert!(empty.is_empty());
+    assert!(!MBR::new_from_pt([1., 1.]).is_empty());
+    assert!(!MBR::new_default().is_empty());
+    assert_eq!(empty.bounds(), None);
+
+    let mut m = MBR::new_empty();
+    m.expand_to_include_point([2., 3.]);
+    assert_eq!(m, MBR::new_from_pt([2., 3.]));
+    let mut m = MBR::new_empty();
+    m.expand_to_include(&MBR::new(0., 0., 1., 1.));
+    assert_eq!(m, MBR::new(0., 0., 1., 1.));
+    m.expand_to_include(&MBR::new_empty());
+    assert_eq!(m, MBR::new(0., 0., 1., 1.));
+}
+
+#[test]
+fn test_from_points() {
+    let pts = [[1., 5.], [-2., 3.], [4., -1.]];
+    assert_eq!(MBR::from_points(pts.iter().copied()), Some(MBR::new(-2., -1., 4., 5.)));
+    assert_eq!(MBR::from_points(Vec::<[f64; 2]>::new()), None);
+
+    let m: MBR = pts.iter().copied().collect();
+    assert_eq!(m, MBR::new(-2., -1., 4., 5.));
+    let none: MBR = Vec::<[f64; 2]>::new().into_iter().collect();
+    assert!(none.is_empty());
+
+    let boxes = vec![MBR::new(0., 0., 1., 1.), MBR::new(3., -2., 4., 0.)];
+    let m: MBR = boxes.iter().copied().collect();
+    assert_eq!(m, MBR::new(0., -2., 4., 1.));
+    assert_eq!(boxes.iter().sum::<MBR>(), m);
+    assert_eq!(boxes.into_iter().sum::<MBR>(), m);
+    assert!(Vec::<MBR>::new().into_iter().sum::<MBR>().is_empty());
+
+    let mut m = MBR::new_from_pt([0., 0.]);
+    m.extend([[1., 1.], [-1., 2.]]);
+    m.extend([MBR::new(5., 5., 6., 6.)]);
+    assert_eq!(m, MBR::new(-1., 0., 6., 6.));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_from_points() {
+    let pts: Vec<[f64; 2]> = (0..10_000).map(|i| [(i % 97) as f64, -((i % 89) as f64)]).collect();
+    assert_eq!(MBR::par_from_points(&pts), MBR::from_points(pts.iter().copied()));
+    assert_eq!(MBR::par_from_points(&[]), None);
+    let data = Boxes { boxes: pts.iter().map(|&p| MBR::new_from_pt(p)).collect() };
+    assert_eq!(data.par_extent(), data.extent());
+}
+
+#[test]
+fn test_trimmed_extent() {
+    //a line of points with two gps spikes
+    let mut pts: Vec<[f64; 2]> = (0..100).map(|i| [i as f64, (i % 10) as f64]).collect();
+    pts[40] = [1e6, 5.];
+    pts[70] = [50., -1e6];
+
+    let (mbr, excluded) = MBR::trimmed_extent(&pts, Trim::Mad(3.)).unwrap();
+    assert_eq!(excluded, vec![40, 70]);
+    assert_eq!(mbr, MBR::new(0., 0., 99., 9.));
+
+    //percentiles 1-99 of 100 values interpolate between the two lowest and the two highest,
+    //so x = 0 and both spikes are excluded but x = 99 stays below the bound the x spike raises
+    let (mbr, excluded) = MBR::trimmed_extent(&pts, Trim::Percentile(1., 99.)).unwrap();
+    assert_eq!(excluded, vec![0, 40, 70]);
+    assert_eq!(mbr, MBR::new(1., 0., 99., 9.));
+    let (all, none) = MBR::trimmed_extent(&pts, Trim::Percentile(100., 0.)).unwrap();
+    assert!(none.is_empty());
+    assert_eq!(Some(all), MBR::from_points(pts.iter().copied()));
+
+    let nan = [[1., 1.], [f64::NAN, 2.], [3., 3.]];
+    let expects = (MBR::new(1., 1., 3., 3.), vec![1]);
+    assert_eq!(MBR::trimmed_extent(&nan, Trim::Mad(2.)), Some(expects));
+    assert_eq!(MBR::trimmed_extent(&[], Trim::Mad(3.)), None);
+    //zero deviation keeps only the median
+    let same = [[2., 2.], [2., 2.], [2., 2.], [9., 2.]];
+    let expects = (MBR::new_from_pt([2., 2.]), vec![3]);
+    assert_eq!(MBR::trimmed_extent(&same, Trim::Mad(3.)), Some(expects));
+}
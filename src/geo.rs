@@ -0,0 +1,143 @@
+use crate::{Bounds, MBR};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+impl From<Coord<f64>> for MBR {
+    fn from(c: Coord<f64>) -> Self {
+        MBR::new_from_pt([c.x, c.y])
+    }
+}
+
+impl From<Point<f64>> for MBR {
+    fn from(p: Point<f64>) -> Self {
+        p.0.into()
+    }
+}
+
+impl From<Rect<f64>> for MBR {
+    fn from(r: Rect<f64>) -> Self {
+        let (min, max) = (r.min(), r.max());
+        MBR::new_raw(min.x, min.y, max.x, max.y)
+    }
+}
+
+impl From<MBR> for Rect<f64> {
+    fn from(mbr: MBR) -> Self {
+        Rect::new(Coord { x: mbr.minx, y: mbr.miny }, Coord { x: mbr.maxx, y: mbr.maxy })
+    }
+}
+
+impl From<MBR> for Polygon<f64> {
+    fn from(mbr: MBR) -> Self {
+        Rect::from(mbr).to_polygon()
+    }
+}
+
+impl MBR {
+    ///Lower left corner as coordinate
+    pub fn min_coord(&self) -> Coord<f64> {
+        Coord { x: self.minx, y: self.miny }
+    }
+
+    ///Upper right corner as coordinate
+    pub fn max_coord(&self) -> Coord<f64> {
+        Coord { x: self.maxx, y: self.maxy }
+    }
+}
+
+///Bounds of coordinates
+fn coords_bounds<'a, I: Iterator<Item = &'a Coord<f64>>>(coords: I) -> Option<MBR> {
+    let mut iter = coords;
+    let first = iter.next()?;
+    let mut mbr = MBR::new_from_pt([first.x, first.y]);
+    for c in iter {
+        mbr.expand_to_include_point([c.x, c.y]);
+    }
+    Some(mbr)
+}
+
+impl Bounds for Coord<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
+
+impl Bounds for Point<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
+
+impl Bounds for Line<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        coords_bounds([self.start, self.end].iter())
+    }
+}
+
+impl Bounds for LineString<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        coords_bounds(self.0.iter())
+    }
+}
+
+///Holes lie within the exterior ring
+impl Bounds for Polygon<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        self.exterior().bounds()
+    }
+}
+
+impl Bounds for Rect<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
+
+impl Bounds for Triangle<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        coords_bounds(self.to_array().iter())
+    }
+}
+
+impl Bounds for MultiPoint<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        self.0.bounds()
+    }
+}
+
+impl Bounds for MultiLineString<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        self.0.bounds()
+    }
+}
+
+impl Bounds for MultiPolygon<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        self.0.bounds()
+    }
+}
+
+impl Bounds for GeometryCollection<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        self.0.bounds()
+    }
+}
+
+impl Bounds for Geometry<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        match self {
+            Geometry::Point(g) => g.bounds(),
+            Geometry::Line(g) => g.bounds(),
+            Geometry::LineString(g) => g.bounds(),
+            Geometry::Polygon(g) => g.bounds(),
+            Geometry::MultiPoint(g) => g.bounds(),
+            Geometry::MultiLineString(g) => g.bounds(),
+            Geometry::MultiPolygon(g) => g.bounds(),
+            Geometry::GeometryCollection(g) => g.bounds(),
+            Geometry::Rect(g) => g.bounds(),
+            Geometry::Triangle(g) => g.bounds(),
+        }
+    }
+}
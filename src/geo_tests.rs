@@ -0,0 +1,48 @@
+use super::*;
+use geo_types::{
+    coord, line_string, point, polygon, Geometry, GeometryCollection, Line, MultiPolygon, Polygon,
+    Rect,
+};
+
+#[test]
+fn test_geo_conversions() {
+    let mbr = MBR::new(3., 4., 1., 2.);
+    let rect: Rect<f64> = mbr.into();
+    assert_eq!((rect.min(), rect.max()), (coord! {x: 1., y: 2.}, coord! {x: 3., y: 4.}));
+    assert_eq!(MBR::from(rect), mbr);
+    assert_eq!((mbr.min_coord(), mbr.max_coord()), (rect.min(), rect.max()));
+
+    let poly: Polygon<f64> = mbr.into();
+    assert_eq!(poly.exterior().0.len(), 5);
+    assert_eq!(poly.bounds(), Some(mbr));
+
+    assert_eq!(MBR::from(coord! {x: 5., y: 6.}), MBR::new_from_pt([5., 6.]));
+    assert_eq!(MBR::from(point! {x: 5., y: 6.}), MBR::new_from_pt([5., 6.]));
+}
+
+#[test]
+fn test_geo_bounds() {
+    let ln = line_string![(x: 0., y: 0.), (x: 4., y: -2.), (x: 2., y: 5.)];
+    assert_eq!(ln.bounds(), Some(MBR::new(0., -2., 4., 5.)));
+    let seg = Line::new(coord! {x: 3., y: 1.}, coord! {x: -1., y: 2.});
+    assert_eq!(seg.bounds(), Some(MBR::new(-1., 1., 3., 2.)));
+
+    let poly = polygon![
+        exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+        interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.)]],
+    ];
+    assert_eq!(poly.bounds(), Some(MBR::new(0., 0., 10., 10.)));
+    let empty = MultiPolygon::<f64>(vec![]);
+    assert_eq!(empty.bounds(), None);
+
+    let geoms: Vec<Geometry<f64>> = vec![
+        point! {x: -3., y: 1.}.into(),
+        ln.into(),
+        poly.into(),
+        Geometry::GeometryCollection(GeometryCollection::<f64>(vec![])),
+    ];
+    assert_eq!(geoms.bounds(), Some(MBR::new(-3., -2., 10., 10.)));
+    let data = Boxes::from_bounds(&geoms);
+    assert_eq!(data.boxes.len(), 3);
+    assert!(data[2].contains_point([5., 5.]));
+}
@@ -0,0 +1,258 @@
+use crate::{Boxes, MBR};
+use std::collections::HashMap;
+
+///Boxes overlapping more cells than this are kept in an overflow list
+const MAX_CELLS: f64 = 1024.0;
+
+///Uniform grid spatial hash of boxes keyed by id.
+///
+///A box is stored in every cell it overlaps, so cells should be about the size of
+///the boxes. Boxes spanning more than 1024 cells (or with non finite bounds) are kept
+///in an overflow list that every query scans. Buckets are reused across `rebuild`s,
+///which makes per frame rebuilds cheap; buckets left empty are dropped.
+#[derive(Clone, Debug)]
+pub struct GridHash {
+    cell_size: [f64; 2],
+    cells: HashMap<(i64, i64), Vec<(usize, MBR)>>,
+    overflow: Vec<(usize, MBR)>,
+    boxes: HashMap<usize, MBR>,
+}
+
+impl GridHash {
+    ///New grid hash with square cells
+    pub fn new(cell_size: f64) -> GridHash {
+        GridHash::new_xy(cell_size, cell_size)
+    }
+
+    ///New grid hash with cells of size dx by dy
+    pub fn new_xy(dx: f64, dy: f64) -> GridHash {
+        assert!(dx > 0.0 && dy > 0.0, "grid cell size must be positive");
+        GridHash {
+            cell_size: [dx, dy],
+            cells: HashMap::new(),
+            overflow: vec![],
+            boxes: HashMap::new(),
+        }
+    }
+
+    ///Cell size [dx, dy]
+    pub fn cell_size(&self) -> [f64; 2] {
+        self.cell_size
+    }
+
+    ///Number of boxes
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    ///Checks if grid has no boxes
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    ///Number of cell buckets, including empty ones kept by `clear`
+    pub fn num_buckets(&self) -> usize {
+        self.cells.len()
+    }
+
+    ///Box with id
+    pub fn get(&self, id: usize) -> Option<MBR> {
+        self.boxes.get(&id).copied()
+    }
+
+    ///Cell (col, row) containing point
+    pub fn cell(&self, pt: [f64; 2]) -> (i64, i64) {
+        (
+            (pt[0] / self.cell_size[0]).floor() as i64,
+            (pt[1] / self.cell_size[1]).floor() as i64,
+        )
+    }
+
+    ///Bounds of cell (col, row)
+    pub fn cell_bounds(&self, cell: (i64, i64)) -> MBR {
+        let [dx, dy] = self.cell_size;
+        let (x, y) = (cell.0 as f64 * dx, cell.1 as f64 * dy);
+        MBR::new_raw(x, y, x + dx, y + dy)
+    }
+
+    ///Removes all boxes, keeping bucket allocations for reuse until `shrink` or `rebuild`
+    pub fn clear(&mut self) {
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+        self.overflow.clear();
+        self.boxes.clear();
+    }
+
+    ///Drops empty buckets
+    pub fn shrink(&mut self) {
+        self.cells.retain(|_, bucket| !bucket.is_empty());
+    }
+
+    ///Clears grid and inserts boxes with their index as id, reusing buckets of
+    ///cells that are still occupied and dropping the rest
+    pub fn rebuild(&mut self, boxes: &Boxes) {
+        self.clear();
+        for (id, mbr) in boxes.boxes.iter().enumerate() {
+            self.insert(id, *mbr);
+        }
+        self.shrink();
+    }
+
+    ///Inserts box with id into every cell it overlaps, replaces the box of an existing id
+    pub fn insert(&mut self, id: usize, mbr: MBR) {
+        if self.boxes.contains_key(&id) {
+            self.remove(id);
+        }
+        match self.cell_span(&mbr) {
+            Some((c0, c1)) => {
+                for col in c0.0..=c1.0 {
+                    for row in c0.1..=c1.1 {
+                        self.cells.entry((col, row)).or_default().push((id, mbr));
+                    }
+                }
+            }
+            None => self.overflow.push((id, mbr)),
+        }
+        self.boxes.insert(id, mbr);
+    }
+
+    ///Removes box with id, returns its box if found
+    pub fn remove(&mut self, id: usize) -> Option<MBR> {
+        let mbr = self.boxes.remove(&id)?;
+        let remove_id = |bucket: &mut Vec<(usize, MBR)>| {
+            if let Some(i) = bucket.iter().position(|it| it.0 == id) {
+                bucket.swap_remove(i);
+            }
+        };
+        match self.cell_span(&mbr) {
+            Some((c0, c1)) => {
+                for col in c0.0..=c1.0 {
+                    for row in c0.1..=c1.1 {
+                        if let Some(bucket) = self.cells.get_mut(&(col, row)) {
+                            remove_id(bucket);
+                            if bucket.is_empty() {
+                                self.cells.remove(&(col, row));
+                            }
+                        }
+                    }
+                }
+            }
+            None => remove_id(&mut self.overflow),
+        }
+        Some(mbr)
+    }
+
+    ///Ids of boxes that intersect window, sorted and without duplicates
+    pub fn query(&self, window: &MBR) -> Vec<usize> {
+        let mut results = vec![];
+        let (c0, c1) = (self.cell(window.ll()), self.cell(window.ur()));
+        let ncells = (c1.0 as f64 - c0.0 as f64 + 1.0) * (c1.1 as f64 - c0.1 as f64 + 1.0);
+        if ncells > self.cells.len() as f64 {
+            //window covers more cells than are occupied
+            for bucket in self.cells.values() {
+                collect(bucket, window, &mut results);
+            }
+        } else {
+            for col in c0.0..=c1.0 {
+                for row in c0.1..=c1.1 {
+                    if let Some(bucket) = self.cells.get(&(col, row)) {
+                        collect(bucket, window, &mut results);
+                    }
+                }
+            }
+        }
+        collect(&self.overflow, window, &mut results);
+        results.sort_unstable();
+        results.dedup();
+        results
+    }
+
+    ///Ids of boxes that contain point
+    pub fn query_point(&self, pt: [f64; 2]) -> Vec<usize> {
+        let mut results = vec![];
+        if let Some(bucket) = self.cells.get(&self.cell(pt)) {
+            collect(bucket, &MBR::new_from_pt(pt), &mut results);
+        }
+        collect(&self.overflow, &MBR::new_from_pt(pt), &mut results);
+        results.sort_unstable();
+        results
+    }
+
+    ///k nearest boxes to point as (id, distance) sorted by distance.
+    ///Rings of cells around the point are searched until no unvisited box can be nearer.
+    pub fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        let query = MBR::new_from_pt(pt);
+        let mut found: HashMap<usize, f64> = HashMap::new();
+        let visit = |found: &mut HashMap<usize, f64>, bucket: &[(usize, MBR)]| {
+            for &(id, mbr) in bucket {
+                found.entry(id).or_insert_with(|| mbr.distance(&query));
+            }
+        };
+        visit(&mut found, &self.overflow);
+        let c = self.cell(pt);
+        let mut r = 0i64;
+        while k > 0 && found.len() < self.boxes.len() {
+            if (8 * r) as usize > self.cells.len() {
+                //ring has more cells than are occupied
+                for bucket in self.cells.values() {
+                    visit(&mut found, bucket);
+                }
+                break;
+            }
+            let mut ring = Vec::with_capacity((8 * r).max(1) as usize);
+            for col in c.0 - r..=c.0 + r {
+                ring.push((col, c.1 - r));
+                if r > 0 {
+                    ring.push((col, c.1 + r));
+                }
+            }
+            for row in c.1 - r + 1..c.1 + r {
+                ring.push((c.0 - r, row));
+                ring.push((c.0 + r, row));
+            }
+            for cell in ring {
+                if let Some(bucket) = self.cells.get(&cell) {
+                    visit(&mut found, bucket);
+                }
+            }
+            //boxes not found lie outside the searched square
+            let lo = self.cell_bounds((c.0 - r, c.1 - r));
+            let hi = self.cell_bounds((c.0 + r, c.1 + r));
+            let bound = (pt[0] - lo.minx)
+                .min(hi.maxx - pt[0])
+                .min(pt[1] - lo.miny)
+                .min(hi.maxy - pt[1]);
+            if found.len() >= k {
+                let mut dists: Vec<f64> = found.values().copied().collect();
+                let (_, kth, _) = dists.select_nth_unstable_by(k - 1, |a, b| a.total_cmp(b));
+                if *kth <= bound {
+                    break;
+                }
+            }
+            r += 1;
+        }
+
+        let mut results: Vec<(usize, f64)> = found.into_iter().collect();
+        results.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        results.truncate(k);
+        results
+    }
+
+    ///First and last cells overlapped by mbr, None if it spans too many cells for buckets
+    fn cell_span(&self, mbr: &MBR) -> Option<((i64, i64), (i64, i64))> {
+        let [dx, dy] = self.cell_size;
+        let cols = (mbr.maxx / dx).floor() - (mbr.minx / dx).floor() + 1.0;
+        let rows = (mbr.maxy / dy).floor() - (mbr.miny / dy).floor() + 1.0;
+        let ncells = cols * rows;
+        if !(ncells.is_finite() && ncells <= MAX_CELLS) {
+            return None;
+        }
+        Some((self.cell(mbr.ll()), self.cell(mbr.ur())))
+    }
+}
+
+///Collects ids of bucket boxes intersecting window
+fn collect(bucket: &[(usize, MBR)], window: &MBR, results: &mut Vec<usize>) {
+    results.extend(bucket.iter().filter(|it| it.1.intersects(window)).map(|it| it.0));
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+#[test]
+fn test_grid_hash() {
+    let mut grid = GridHash::new(10.);
+    assert!(grid.is_empty());
+    assert_eq!(grid.cell_size(), [10., 10.]);
+    assert_eq!(grid.cell([15., -0.5]), (1, -1));
+    assert_eq!(grid.cell_bounds((1, -1)), MBR::new(10., -10., 20., 0.));
+
+    let data: Boxes = vec![
+        [1, 1, 4, 4],
+        [8, 8, 12, 12],   //spans 4 cells
+        [-15, -5, 25, 3], //spans 8 cells
+        [50, 50, 51, 51],
+        [10, 10, 10, 10], //point on cell corner
+    ]
+    .into();
+    grid.rebuild(&data);
+    assert_eq!(grid.len(), 5);
+    assert_eq!(grid.get(1), Some(data[1]));
+
+    assert_eq!(grid.query(&MBR::new(0., 0., 20., 20.)), vec![0, 1, 2, 4]);
+    assert_eq!(grid.query(&MBR::new(9., 9., 9.5, 9.5)), vec![1]);
+    assert_eq!(grid.query(&MBR::new(-100., -100., 100., 100.)), vec![0, 1, 2, 3, 4]);
+    assert!(grid.query(&MBR::new(30., 30., 40., 40.)).is_empty());
+    assert_eq!(grid.query_point([10., 10.]), vec![1, 4]);
+    assert_eq!(grid.query_point([2., 2.]), vec![0, 2]);
+    assert!(grid.query_point([30., 30.]).is_empty());
+
+    //moving and removing
+    grid.insert(2, MBR::new(45., 45., 55., 55.));
+    assert_eq!(grid.query_point([2., 2.]), vec![0]);
+    assert_eq!(grid.query(&MBR::new(49., 49., 52., 52.)), vec![2, 3]);
+    assert_eq!(grid.remove(3), Some(data[3]));
+    assert_eq!(grid.remove(3), None);
+    assert_eq!(grid.query(&MBR::new(49., 49., 52., 52.)), vec![2]);
+    assert_eq!(grid.len(), 4);
+
+    //per frame rebuild
+    let moved = Boxes { boxes: data.boxes.iter().map(|b| b.translate(100., 0.)).collect() };
+    grid.rebuild(&moved);
+    assert!(grid.query(&MBR::new(0., 0., 20., 20.)).is_empty());
+    assert_eq!(grid.query(&MBR::new(100., 0., 120., 20.)), vec![0, 1, 2, 4]);
+
+    let grid = GridHash::new_xy(5., 20.);
+    assert_eq!(grid.cell([12., 12.]), (2, 0));
+}
+
+#[test]
+fn test_grid_hash_buckets() {
+    let mut grid = GridHash::new(10.);
+    grid.insert(0, MBR::new(1., 1., 4., 4.));
+    grid.insert(1, MBR::new(8., 8., 12., 12.));
+    assert_eq!(grid.num_buckets(), 4);
+    grid.remove(1);
+    assert_eq!(grid.num_buckets(), 1);
+    //moving a box leaves no bucket behind
+    for i in 0..100 {
+        grid.insert(0, MBR::new(i as f64 * 10., 0., i as f64 * 10. + 1., 1.));
+    }
+    assert_eq!(grid.num_buckets(), 1);
+    assert_eq!(grid.query_point([990.5, 0.5]), vec![0]);
+
+    grid.clear();
+    assert_eq!(grid.num_buckets(), 1);
+    grid.shrink();
+    assert_eq!(grid.num_buckets(), 0);
+
+    let data: Boxes = vec![[1, 1, 4, 4], [8, 8, 12, 12]].into();
+    grid.rebuild(&data);
+    let moved = Boxes { boxes: data.boxes.iter().map(|b| b.translate(100., 0.)).collect() };
+    grid.rebuild(&moved);
+    assert_eq!(grid.num_buckets(), 4);
+}
+
+#[test]
+fn test_grid_hash_overflow() {
+    let inf = f64::INFINITY;
+    let mut grid = GridHash::new(1.);
+    grid.insert(0, MBR::new(0.5, 0.5, 0.6, 0.6));
+    grid.insert(1, MBR::new(-inf, -inf, inf, inf));
+    grid.insert(2, MBR::new(-1e12, 0., 1e12, 1.));
+    grid.insert(3, MBR::new(5., 5., 6., 6.));
+    assert_eq!(grid.num_buckets(), 5);
+
+    assert_eq!(grid.query(&MBR::new(0., 0., 1., 1.)), vec![0, 1, 2]);
+    assert_eq!(grid.query(&MBR::new(-inf, -inf, inf, inf)), vec![0, 1, 2, 3]);
+    assert_eq!(grid.query_point([1e9, 0.5]), vec![1, 2]);
+    assert_eq!(grid.query_point([5.5, 5.5]), vec![1, 3]);
+    let ids: Vec<usize> = grid.nearest([5.5, 3.], 4).into_iter().map(|it| it.0).collect();
+    assert_eq!(ids, vec![1, 2, 3, 0]);
+
+    assert_eq!(grid.remove(1), Some(MBR::new(-inf, -inf, inf, inf)));
+    assert_eq!(grid.query_point([5.5, 5.5]), vec![3]);
+    //moving into and out of the overflow list
+    grid.insert(2, MBR::new(2., 2., 3., 3.));
+    assert_eq!(grid.query_point([1e9, 0.5]), Vec::<usize>::new());
+    grid.insert(3, MBR::new(0., 0., 100., 100.));
+    assert_eq!(grid.query_point([50., 50.]), vec![3]);
+    assert_eq!(grid.num_buckets(), 5);
+    assert_eq!(grid.len(), 3);
+}
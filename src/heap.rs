@@ -0,0 +1,35 @@
+use std::cmp::Ordering;
+
+///Binary heap entry popped nearest first, ties popped by largest item
+pub(crate) struct MinDist<T> {
+    pub dist: f64,
+    pub item: T,
+}
+
+impl<T> MinDist<T> {
+    pub fn new(dist: f64, item: T) -> MinDist<T> {
+        MinDist { dist, item }
+    }
+}
+
+impl<T: Ord> PartialEq for MinDist<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for MinDist<T> {}
+
+impl<T: Ord> PartialOrd for MinDist<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for MinDist<T> {
+    //reversed distance for a min heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+            .then_with(|| self.item.cmp(&other.item))
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{Bounds, Boxes, GridHash, IndexedMBR, PackedRTree, QuadTree, MBR};
+use rstar::{RTree, RTreeObject};
+use std::collections::HashMap;
+
+///Read only spatial queries over boxes keyed by id
+pub trait SpatialQuery {
+    ///Ids of boxes that intersect window
+    fn query(&self, window: &MBR) -> Vec<usize>;
+
+    ///Ids of boxes that contain point
+    fn query_point(&self, pt: [f64; 2]) -> Vec<usize> {
+        self.query(&MBR::new_from_pt(pt))
+    }
+
+    ///k nearest boxes to point as (id, distance) sorted by distance
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)>;
+}
+
+///Spatial index over boxes keyed by id
+pub trait SpatialIndex: SpatialQuery {
+    ///Inserts box with id, replaces the box of an existing id
+    fn insert(&mut self, id: usize, mbr: MBR);
+
+    ///Removes box with id, returns true if found
+    fn remove(&mut self, id: usize) -> bool;
+
+    ///Inserts bounds of geometry with id, returns false for an empty geometry
+    fn insert_bounds<B: Bounds + ?Sized>(&mut self, id: usize, geom: &B) -> bool
+        where
+            Self: Sized,
+    {
+        match geom.bounds() {
+            Some(mbr) => {
+                self.insert(id, mbr);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+///rstar tree of boxes keyed by id, with the box of each id so that inserts replace
+///and removes need only the id
+#[derive(Clone)]
+pub struct RTreeIndex {
+    tree: RTree<IndexedMBR>,
+    boxes: HashMap<usize, MBR>,
+}
+
+impl RTreeIndex {
+    ///New empty index
+    pub fn new() -> RTreeIndex {
+        RTreeIndex { tree: RTree::new(), boxes: HashMap::new() }
+    }
+
+    ///Underlying rstar tree
+    pub fn tree(&self) -> &RTree<IndexedMBR> {
+        &self.tree
+    }
+
+    ///Number of boxes
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    ///Checks if index has no boxes
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    ///Box with id
+    pub fn get(&self, id: usize) -> Option<MBR> {
+        self.boxes.get(&id).copied()
+    }
+}
+
+impl Default for RTreeIndex {
+    fn default() -> Self {
+        RTreeIndex::new()
+    }
+}
+
+///Bulk loads boxes with their index as id
+impl From<&Boxes> for RTreeIndex {
+    fn from(boxes: &Boxes) -> Self {
+        RTreeIndex { tree: boxes.rtree(), boxes: boxes.boxes.iter().copied().enumerate().collect() }
+    }
+}
+
+///SpatialQuery for rstar tree, an id may appear once per inserted box
+impl SpatialQuery for RTree<IndexedMBR> {
+    fn query(&self, window: &MBR) -> Vec<usize> {
+        self.locate_in_envelope_intersecting(&window.envelope())
+            .map(|it| it.index)
+            .collect()
+    }
+
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        let query = MBR::new_from_pt(pt);
+        self.nearest_neighbor_iter(&pt)
+            .take(k)
+            .map(|it| (it.index, it.mbr.distance(&query)))
+            .collect()
+    }
+}
+
+///SpatialQuery for rstar tree index
+impl SpatialQuery for RTreeIndex {
+    fn query(&self, window: &MBR) -> Vec<usize> {
+        self.tree.query(window)
+    }
+
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        SpatialQuery::nearest(&self.tree, pt, k)
+    }
+}
+
+///SpatialIndex for rstar tree index
+impl SpatialIndex for RTreeIndex {
+    fn insert(&mut self, id: usize, mbr: MBR) {
+        SpatialIndex::remove(self, id);
+        self.tree.insert(IndexedMBR { mbr, index: id });
+        self.boxes.insert(id, mbr);
+    }
+
+    fn remove(&mut self, id: usize) -> bool {
+        match self.boxes.remove(&id) {
+            Some(mbr) => self.tree.remove(&IndexedMBR { mbr, index: id }).is_some(),
+            None => false,
+        }
+    }
+}
+
+///SpatialQuery for quadtree
+impl SpatialQuery for QuadTree {
+    fn query(&self, window: &MBR) -> Vec<usize> {
+        QuadTree::query(self, window)
+    }
+
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        QuadTree::nearest(self, pt, k)
+    }
+}
+
+///SpatialIndex for quadtree
+impl SpatialIndex for QuadTree {
+    fn insert(&mut self, id: usize, mbr: MBR) {
+        QuadTree::insert(self, id, mbr)
+    }
+
+    fn remove(&mut self, id: usize) -> bool {
+        QuadTree::remove(self, id).is_some()
+    }
+}
+
+///SpatialQuery for grid hash
+impl SpatialQuery for GridHash {
+    fn query(&self, window: &MBR) -> Vec<usize> {
+        GridHash::query(self, window)
+    }
+
+    fn query_point(&self, pt: [f64; 2]) -> Vec<usize> {
+        GridHash::query_point(self, pt)
+    }
+
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        GridHash::nearest(self, pt, k)
+    }
+}
+
+///SpatialIndex for grid hash
+impl SpatialIndex for GridHash {
+    fn insert(&mut self, id: usize, mbr: MBR) {
+        GridHash::insert(self, id, mbr)
+    }
+
+    fn remove(&mut self, id: usize) -> bool {
+        GridHash::remove(self, id).is_some()
+    }
+}
+
+///SpatialQuery for packed tree, ids are indices of the boxes it was built from
+impl<D: AsRef<[u8]>> SpatialQuery for PackedRTree<D> {
+    fn query(&self, window: &MBR) -> Vec<usize> {
+        self.search(window)
+    }
+
+    fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        self.neighbors(pt, k)
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::test_util::{random_boxes, sorted};
+
+fn check_query<T: SpatialQuery + ?Sized>(index: &T, data: &Boxes, removed: &[usize]) {
+    let live = |i: &usize| !removed.contains(i);
+    for window in [MBR::new(10., 10., 30., 25.), MBR::new(-5., 50., 200., 52.)].iter() {
+        let expects: Vec<usize> =
+            (0..data.boxes.len()).filter(live).filter(|&i| data[i].intersects(window)).collect();
+        assert_eq!(sorted(index.query(window)), expects);
+    }
+    let pt = [40.2, 60.1];
+    let expects: Vec<usize> =
+        (0..data.boxes.len()).filter(live).filter(|&i| data[i].contains_point(pt)).collect();
+    assert_eq!(sorted(index.query_point(pt)), expects);
+
+    for &pt in [[50., 50.], [-30., 120.], [77.7, 3.3]].iter() {
+        let found = index.nearest(pt, 7);
+        let expects: Vec<(usize, f64)> = data
+            .knn(pt, data.boxes.len(), Metric::Euclidean)
+            .into_iter()
+            .filter(|r| live(&r.0))
+            .take(7)
+            .collect();
+        let dists = |v: &[(usize, f64)]| v.iter().map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(dists(&found), dists(&expects));
+        for &(i, d) in &found {
+            assert_eq!(data[i].distance(&MBR::new_from_pt(pt)), d);
+        }
+    }
+}
+
+fn check_index<T: SpatialIndex + ?Sized>(index: &mut T, data: &Boxes) {
+    for (i, b) in data.boxes.iter().enumerate() {
+        index.insert(i, *b);
+    }
+    check_query(index, data, &[]);
+
+    //inserting an existing id replaces its box
+    index.insert(7, MBR::new(500., 500., 501., 501.));
+    assert_eq!(index.query(&MBR::new(499., 499., 502., 502.)), vec![7]);
+    assert!(index.query(&data[7]).iter().all(|&i| i != 7));
+    index.insert(7, data[7]);
+    assert_eq!(index.query(&data[7]).iter().filter(|&&i| i == 7).count(), 1);
+    check_query(index, data, &[]);
+
+    let removed: Vec<usize> = (0..data.boxes.len()).step_by(4).collect();
+    for &i in &removed {
+        assert!(index.remove(i));
+    }
+    assert!(!index.remove(0));
+    check_query(index, data, &removed);
+}
+
+#[test]
+fn test_spatial_index() {
+    let data = random_boxes(300, 3, 5.);
+    check_query(&data.rtree(), &data, &[]);
+    check_query(&RTreeIndex::from(&data), &data, &[]);
+    check_query(&PackedRTree::new(&data, 16), &data, &[]);
+
+    //backends are interchangeable behind the trait
+    let mut backends: Vec<Box<dyn SpatialIndex>> = vec![
+        Box::new(RTreeIndex::new()),
+        Box::new(QuadTree::new(MBR::new(0., 0., 100., 100.), QuadTreeConfig::default())),
+        Box::new(GridHash::new(8.)),
+        Box::new(GridHash::new(3.)),
+    ];
+    for index in backends.iter_mut() {
+        check_index(index.as_mut(), &data);
+    }
+}
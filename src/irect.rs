@@ -0,0 +1,126 @@
+use crate::{Rounding, Window, MBR};
+
+///Integer pixel rectangle with half-open intervals `[x0, x1) x [y0, y1)`.
+///
+///Unlike `MBR`, rectangles that share an edge such as `[0, 10)` and `[10, 20)` do not
+///intersect, and a rectangle with `x1 <= x0` or `y1 <= y0` is empty. Equality compares
+///coordinates, so empty rects at different places are not equal; compare `is_empty` instead.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct IRect {
+    pub x0: i64,
+    pub y0: i64,
+    pub x1: i64,
+    pub y1: i64,
+}
+
+impl IRect {
+    ///New rect given corners (x0, y0) & (x1, y1), in any order
+    pub fn new(x0: i64, y0: i64, x1: i64, y1: i64) -> IRect {
+        IRect { x0: x0.min(x1), y0: y0.min(y1), x1: x0.max(x1), y1: y0.max(y1) }
+    }
+
+    ///New rect at (x, y) of width x height, empty if width or height is negative
+    pub fn from_size(x: i64, y: i64, width: i64, height: i64) -> IRect {
+        IRect { x0: x, y0: y, x1: x.saturating_add(width.max(0)), y1: y.saturating_add(height.max(0)) }
+    }
+
+    ///Rect of mbr with edges rounded by rounding, `Rounding::Outward` covers
+    ///every pixel the mbr touches
+    pub fn from_mbr(mbr: &MBR, rounding: Rounding) -> IRect {
+        let (x0, y0) = (rounding.start(mbr.minx), rounding.start(mbr.miny));
+        let (x1, y1) = (rounding.end(mbr.maxx), rounding.end(mbr.maxy));
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        IRect { x0, y0, x1: (x1 as i64).max(x0), y1: (y1 as i64).max(y0) }
+    }
+
+    ///Closed MBR spanning the rect edges
+    pub fn to_mbr(&self) -> MBR {
+        MBR::new_raw(self.x0 as f64, self.y0 as f64, self.x1 as f64, self.y1 as f64)
+    }
+
+    ///Width, 0 if empty, saturates at i64::MAX
+    pub fn width(&self) -> i64 {
+        self.x1.saturating_sub(self.x0).max(0)
+    }
+
+    ///Height, 0 if empty, saturates at i64::MAX
+    pub fn height(&self) -> i64 {
+        self.y1.saturating_sub(self.y0).max(0)
+    }
+
+    ///Number of pixels, saturates at i64::MAX
+    pub fn area(&self) -> i64 {
+        self.width().saturating_mul(self.height())
+    }
+
+    ///Checks if rect has no pixels
+    pub fn is_empty(&self) -> bool {
+        self.x1 <= self.x0 || self.y1 <= self.y0
+    }
+
+    ///Contains pixel (x, y)
+    pub fn contains_point(&self, x: i64, y: i64) -> bool {
+        x >= self.x0 && x < self.x1 && y >= self.y0 && y < self.y1
+    }
+
+    ///Contains every pixel of other, an empty rect is contained by any rect
+    pub fn contains(&self, other: &IRect) -> bool {
+        other.is_empty()
+            || (other.x0 >= self.x0
+                && other.y0 >= self.y0
+                && other.x1 <= self.x1
+                && other.y1 <= self.y1)
+    }
+
+    ///Checks if rects share a pixel
+    pub fn intersects(&self, other: &IRect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    ///Shared pixels, None if there are none
+    pub fn intersection(&self, other: &IRect) -> Option<IRect> {
+        let r = IRect {
+            x0: self.x0.max(other.x0),
+            y0: self.y0.max(other.y0),
+            x1: self.x1.min(other.x1),
+            y1: self.y1.min(other.y1),
+        };
+        if r.is_empty() { None } else { Some(r) }
+    }
+
+    ///Smallest rect containing both, empty rects are ignored
+    pub fn union(&self, other: &IRect) -> IRect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+        IRect {
+            x0: self.x0.min(other.x0),
+            y0: self.y0.min(other.y0),
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+        }
+    }
+
+    ///Pixel coordinates (x, y) in row major order
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> {
+        let (x0, x1) = (self.x0, self.x1);
+        (self.y0..self.y1).flat_map(move |y| (x0..x1).map(move |x| (x, y)))
+    }
+}
+
+///Window (col_off, row_off, width, height) as rect
+impl From<Window> for IRect {
+    fn from(w: Window) -> Self {
+        IRect::from_size(w.col_off, w.row_off, w.width, w.height)
+    }
+}
+
+///Rect as window (col_off, row_off, width, height)
+impl From<IRect> for Window {
+    fn from(r: IRect) -> Self {
+        Window::new(r.x0, r.y0, r.width(), r.height())
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn test_irect() {
+    let a = IRect::new(0, 0, 10, 10);
+    let b = IRect::new(10, 0, 20, 10);
+    let c = IRect::from_size(5, 5, 10, 10);
+    assert_eq!(IRect::new(10, 10, 0, 0), a);
+    assert_eq!((c.x1, c.y1, c.width(), c.height(), c.area()), (15, 15, 10, 10, 100));
+
+    //shared edges do not intersect
+    assert!(!a.intersects(&b));
+    assert_eq!(a.intersection(&b), None);
+    assert!(a.to_mbr().intersects(&b.to_mbr()));
+    assert_eq!(a.intersection(&c), Some(IRect::new(5, 5, 10, 10)));
+    assert_eq!(a.union(&b), IRect::new(0, 0, 20, 10));
+    assert_eq!(a.union(&IRect::default()), a);
+    assert_eq!(IRect::default().union(&c), c);
+
+    assert!(a.contains_point(0, 9));
+    assert!(!a.contains_point(10, 5));
+    assert!(a.contains(&IRect::new(2, 2, 10, 10)));
+    assert!(!a.contains(&c));
+    assert!(a.contains(&IRect::new(50, 50, 50, 60)));
+    assert!(IRect::new(3, 3, 3, 8).is_empty());
+    //negative sizes do not flip
+    assert_eq!(IRect::from_size(10, 0, -5, 5), IRect::new(10, 0, 10, 5));
+    assert!(IRect::from(Window::new(10, 0, 5, -5)).is_empty());
+    assert_ne!(IRect::new(3, 3, 3, 8), IRect::default());
+
+    let pixels: Vec<(i64, i64)> = IRect::new(1, 2, 3, 4).iter().collect();
+    assert_eq!(pixels, vec![(1, 2), (2, 2), (1, 3), (2, 3)]);
+    assert_eq!(IRect::new(0, 0, 0, 5).iter().count(), 0);
+}
+
+#[test]
+fn test_irect_mbr() {
+    let mbr = MBR::new(0.5, 1., 3.2, 4.7);
+    assert_eq!(IRect::from_mbr(&mbr, Rounding::Outward), IRect::new(0, 1, 4, 5));
+    assert_eq!(IRect::from_mbr(&mbr, Rounding::Inward), IRect::new(1, 1, 3, 4));
+    assert_eq!(IRect::from_mbr(&mbr, Rounding::Floor), IRect::new(0, 1, 3, 4));
+    assert_eq!(IRect::from_mbr(&mbr, Rounding::Ceil), IRect::new(1, 1, 4, 5));
+    assert_eq!(IRect::from_mbr(&mbr, Rounding::Round), IRect::new(1, 1, 3, 5));
+    assert!(IRect::from_mbr(&MBR::new(0.2, 0.2, 0.8, 0.8), Rounding::Inward).is_empty());
+    assert_eq!(IRect::new(0, 1, 4, 5).to_mbr(), MBR::new(0., 1., 4., 5.));
+
+    let win = Window::new(2, 3, 4, 5);
+    assert_eq!(IRect::from(win), IRect::new(2, 3, 6, 8));
+    assert_eq!(Window::from(IRect::from(win)), win);
+}
+
+#[test]
+fn test_irect_huge() {
+    let huge = IRect::from_mbr(&MBR::new(-1e300, -1e300, 1e300, 1e300), Rounding::Outward);
+    assert_eq!(huge, IRect::new(i64::MIN, i64::MIN, i64::MAX, i64::MAX));
+    assert_eq!(huge.width(), i64::MAX);
+    assert_eq!(huge.height(), i64::MAX);
+    assert_eq!(huge.area(), i64::MAX);
+    assert_eq!(IRect::from_size(i64::MAX - 1, 0, 10, 10), IRect::new(i64::MAX - 1, 0, i64::MAX, 10));
+}
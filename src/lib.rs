@@ -1,11 +1,56 @@
 use math_util::{feq, num, NumCast};
-use rstar::{PointDistance, RTreeObject, AABB};
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt::{Display, Error, Formatter};
 use std::ops;
 use std::ops::Index;
 
+mod bounds;
+mod columns;
+#[cfg(feature = "euclid")]
+mod euclid_types;
+mod extent;
+#[cfg(feature = "geo")]
+mod geo;
+mod grid;
+mod heap;
+mod index;
+mod irect;
+mod metric;
+#[cfg(feature = "mint")]
+mod mint_types;
+mod obb;
+mod order;
+mod packed;
+mod plot;
+mod quadtree;
+mod raster;
+mod ray;
+mod sfc;
+mod svg;
+mod viewport;
+
+pub use bounds::Bounds;
+pub use columns::{mask_indices, BoxColumns};
+pub use extent::Trim;
+pub use grid::GridHash;
+pub use index::{RTreeIndex, SpatialIndex, SpatialQuery};
+pub use irect::IRect;
+pub use metric::Metric;
+pub use obb::OBB;
+pub use order::{cmp_area, cmp_centre_x, cmp_hilbert};
+pub use packed::PackedRTree;
+pub use plot::Plot;
+pub use quadtree::{QuadTree, QuadTreeConfig};
+pub use raster::{GeoTransform, Rounding, Window};
+pub use ray::{ray_cast, Ray, RayHit};
+pub use sfc::{
+    hilbert_index, key_ranges, morton_decode, morton_index, morton_litmax_bigmin, Curve,
+};
+pub use svg::{Style, Svg};
+pub use viewport::{Affine, Fit, Viewport};
+
 ///MBR
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct MBR {
@@ -36,6 +81,18 @@ impl MBR {
         MBR { minx: 0.0, miny: 0.0, maxx: 0.0, maxy: 0.0 }
     }
 
+    ///New empty MBR, inverted infinite bounds that any expand_to_include replaces.
+    ///Measures are not meaningful until then: width and height are -inf, area is +inf
+    ///and centre is NaN, so check is_empty first.
+    pub fn new_empty() -> MBR {
+        MBR {
+            minx: f64::INFINITY,
+            miny: f64::INFINITY,
+            maxx: f64::NEG_INFINITY,
+            maxy: f64::NEG_INFINITY,
+        }
+    }
+
     ///New MBR from array of 4 coordinates [x1, y1, x2, y2]
     pub fn new_from_array(o: [f64; 4]) -> MBR { o.into() }
 
@@ -67,6 +124,12 @@ impl MBR {
     #[inline]
     pub fn height(&self) -> f64 { self.maxy - self.miny }
 
+    ///Checks if bounding box is empty, min greater than max
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.minx > self.maxx || self.miny > self.maxy
+    }
+
     ///Computes area of bounding box.
     #[inline]
     pub fn area(&self) -> f64 {
@@ -127,6 +190,65 @@ impl MBR {
         feq(self.minx, c[0]) && feq(self.miny, c[1])
     }
 
+    ///Compare equality of two bounding boxes, coordinates may differ by at most eps.
+    ///Tolerance predicates (`*_eps`) compare coordinates with `|a - b| <= eps`, so
+    ///boxes that are `equals_eps` also contain and intersect each other with the same eps,
+    ///and an eps of 0 gives the exact predicates.
+    #[inline]
+    pub fn equals_eps(&self, other: &Self, eps: f64) -> bool {
+        (self.maxx - other.maxx).abs() <= eps
+            && (self.maxy - other.maxy).abs() <= eps
+            && (self.minx - other.minx).abs() <= eps
+            && (self.miny - other.miny).abs() <= eps
+    }
+
+    ///Checks if bounding box is a point within eps, the same as
+    ///equals_eps of the point at its centre.
+    #[inline]
+    pub fn is_point_eps(&self, eps: f64) -> bool {
+        self.equals_eps(&MBR::new_from_pt(self.centre()), eps)
+    }
+
+    ///Contains bonding box within eps,
+    ///other may extend past the boundaries by at most eps
+    #[inline]
+    pub fn contains_eps(&self, other: &Self, eps: f64) -> bool {
+        (other.minx >= self.minx - eps)
+            && (other.miny >= self.miny - eps)
+            && (other.maxx <= self.maxx + eps)
+            && (other.maxy <= self.maxy + eps)
+    }
+
+    ///contains x, y within eps
+    #[inline]
+    pub fn contains_xy_eps(&self, x: f64, y: f64, eps: f64) -> bool {
+        (x >= self.minx - eps) && (x <= self.maxx + eps)
+            && (y >= self.miny - eps) && (y <= self.maxy + eps)
+    }
+
+    ///contains point within eps
+    #[inline]
+    pub fn contains_point_eps<P: Into<[f64; 2]>>(&self, pt: P, eps: f64) -> bool {
+        let pt = pt.into();
+        self.contains_xy_eps(pt[0], pt[1], eps)
+    }
+
+    ///Checks if bounding box intersects other within eps,
+    ///boxes at most eps apart intersect
+    #[inline]
+    pub fn intersects_eps(&self, other: &Self, eps: f64) -> bool {
+        !(other.minx > self.maxx + eps
+            || other.maxx < self.minx - eps
+            || other.miny > self.maxy + eps
+            || other.maxy < self.miny - eps)
+    }
+
+    ///Test for disjoint between two mbrs within eps
+    #[inline]
+    pub fn disjoint_eps(&self, m: &Self, eps: f64) -> bool {
+        !self.intersects_eps(m, eps)
+    }
+
     ///Contains bonding box
     ///is true if mbr completely contains other, boundaries may touch
     #[inline]
@@ -143,9 +265,10 @@ impl MBR {
         (x >= self.minx) && (x <= self.maxx) && (y >= self.miny) && (y <= self.maxy)
     }
 
-    ///contains point
+    ///contains point, any point type convertible to [x, y]
     #[inline]
-    pub fn contains_point(&self, pt: [f64; 2]) -> bool {
+    pub fn contains_point<P: Into<[f64; 2]>>(&self, pt: P) -> bool {
+        let pt = pt.into();
         self.contains_xy(pt[0], pt[1])
     }
 
@@ -169,7 +292,8 @@ impl MBR {
     ///completely_contains_point is true if mbr completely contains location with point{x, y}
     ///without touching boundaries
     #[inline]
-    pub fn completely_contains_point(&self, pt: [f64; 2]) -> bool {
+    pub fn completely_contains_point<P: Into<[f64; 2]>>(&self, pt: P) -> bool {
+        let pt = pt.into();
         self.completely_contains_xy(pt[0], pt[1])
     }
 
@@ -196,7 +320,8 @@ impl MBR {
 
     ///intersects point
     #[inline]
-    pub fn intersects_point(&self, pt: &[f64]) -> bool {
+    pub fn intersects_point<P: Into<[f64; 2]>>(&self, pt: P) -> bool {
+        let pt = pt.into();
         self.intersects_xy(pt[0], pt[1])
     }
 
@@ -251,8 +376,9 @@ impl MBR {
         self
     }
 
-    ///Expand to include point(x, y)
-    pub fn expand_to_include_point(&mut self, pt: [f64; 2]) -> &mut Self {
+    ///Expand to include point(x, y), any point type convertible to [x, y]
+    pub fn expand_to_include_point<P: Into<[f64; 2]>>(&mut self, pt: P) -> &mut Self {
+        let pt = pt.into();
         self.expand_to_include_xy(pt[0], pt[1])
     }
 
@@ -260,13 +386,15 @@ impl MBR {
     pub fn expand_to_include_xy(&mut self, x: f64, y: f64) -> &mut Self {
         if x < self.minx {
             self.minx = x
-        } else if x > self.maxx {
+        }
+        if x > self.maxx {
             self.maxx = x
         }
 
         if y < self.miny {
             self.miny = y
-        } else if y > self.maxy {
+        }
+        if y > self.maxy {
             self.maxy = y
         }
         self
@@ -323,6 +451,83 @@ impl MBR {
         (dx * dx) + (dy * dy)
     }
 
+    ///closest point on (or in) bounding box to point
+    pub fn closest_point<P: Into<[f64; 2]>>(&self, pt: P) -> [f64; 2] {
+        let pt = pt.into();
+        [
+            pt[0].max(self.minx).min(self.maxx),
+            pt[1].max(self.miny).min(self.maxy),
+        ]
+    }
+
+    ///farthest corner of bounding box from point
+    pub fn farthest_point<P: Into<[f64; 2]>>(&self, pt: P) -> [f64; 2] {
+        let pt = pt.into();
+        let c = self.centre();
+        [
+            if pt[0] < c[0] { self.maxx } else { self.minx },
+            if pt[1] < c[1] { self.maxy } else { self.miny },
+        ]
+    }
+
+    ///computes dx and dy between the farthest points of two mbrs
+    pub fn max_distance_dxdy(&self, other: &Self) -> (f64, f64) {
+        let dx = (other.maxx - self.minx).abs().max((self.maxx - other.minx).abs());
+        let dy = (other.maxy - self.miny).abs().max((self.maxy - other.miny).abs());
+        (dx, dy)
+    }
+
+    ///max distance computes the distance between the farthest points of two mbrs,
+    ///for a point use `MBR::new_from_pt`
+    pub fn max_distance(&self, other: &Self) -> f64 {
+        let (dx, dy) = self.max_distance_dxdy(other);
+        dx.hypot(dy)
+    }
+
+    ///max distance square computes the squared distance
+    ///between the farthest points of two mbrs
+    pub fn max_distance_square(&self, other: &Self) -> f64 {
+        let (dx, dy) = self.max_distance_dxdy(other);
+        (dx * dx) + (dy * dy)
+    }
+
+    ///hausdorff distance between bounding boxes
+    ///(directed distances are attained at the corners)
+    pub fn hausdorff_distance(&self, other: &Self) -> f64 {
+        let directed = |a: &MBR, b: &MBR| {
+            a.as_poly_array()[..4]
+                .iter()
+                .map(|&pt| b.distance_square(&MBR::new_from_pt(pt)))
+                .fold(0.0, f64::max)
+        };
+        directed(self, other).max(directed(other, self)).sqrt()
+    }
+
+    ///MINMAXDIST (Roussopoulos et al.) from point to bounding box: the smallest
+    ///upper bound on the distance to the nearest object enclosed by the box
+    pub fn minmax_distance<P: Into<[f64; 2]>>(&self, pt: P) -> f64 {
+        self.minmax_distance_square(pt).sqrt()
+    }
+
+    ///squared MINMAXDIST from point to bounding box
+    pub fn minmax_distance_square<P: Into<[f64; 2]>>(&self, pt: P) -> f64 {
+        let pt = pt.into();
+        let c = self.centre();
+        let sq = |v: f64| v * v;
+        // nearer face (rm) and farther face (rM) per axis
+        let rm = [
+            if pt[0] <= c[0] { self.minx } else { self.maxx },
+            if pt[1] <= c[1] { self.miny } else { self.maxy },
+        ];
+        let rmax = [
+            if pt[0] >= c[0] { self.minx } else { self.maxx },
+            if pt[1] >= c[1] { self.miny } else { self.maxy },
+        ];
+        let dx = sq(pt[0] - rm[0]) + sq(pt[1] - rmax[1]);
+        let dy = sq(pt[1] - rm[1]) + sq(pt[0] - rmax[0]);
+        dx.min(dy)
+    }
+
     ///WKT string
     pub fn wkt(&self) -> String {
         format!(
@@ -339,6 +544,42 @@ pub struct Boxes {
     pub boxes: Vec<MBR>
 }
 
+impl Boxes {
+    ///Extent of boxes, None if empty
+    pub fn extent(&self) -> Option<MBR> {
+        self.bounds()
+    }
+
+    ///Boxes from the bounds of geometries, empty geometries are skipped
+    pub fn from_bounds<I>(geoms: I) -> Boxes
+        where
+            I: IntoIterator,
+            I::Item: Bounds,
+    {
+        Boxes { boxes: geoms.into_iter().filter_map(|g| g.bounds()).collect() }
+    }
+
+    ///Bulk loads an rstar tree of boxes tagged with their index
+    pub fn rtree(&self) -> RTree<IndexedMBR> {
+        RTree::bulk_load(
+            self.boxes.iter().enumerate()
+                .map(|(index, &mbr)| IndexedMBR { mbr, index })
+                .collect()
+        )
+    }
+
+    ///Nearest box hit by ray as (index, hit), see `ray_cast` to reuse a tree
+    pub fn ray_cast(&self, ray: &Ray) -> Option<(usize, RayHit)> {
+        ray_cast(&self.rtree(), ray)
+    }
+}
+
+///MBR tagged with its index in a collection of boxes
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndexedMBR {
+    pub mbr: MBR,
+    pub index: usize,
+}
 
 impl<T> From<(T, T, T, T)> for MBR
     where
@@ -425,30 +666,29 @@ pub trait BBox {
 ///Eq for MBR
 impl Eq for MBR {}
 
-///PartialEq for MBR
+///PartialEq for MBR, exact and consistent with Ord (see `equals` for tolerance)
 impl PartialEq for MBR {
     fn eq(&self, other: &Self) -> bool {
-        self.equals(other)
+        self.cmp(other) == Ordering::Equal
     }
 }
 
-///Ord for MBR
+///Ord for MBR, lexicographic total order over minx, miny, maxx, maxy
 impl Ord for MBR {
     fn cmp(&self, other: &Self) -> Ordering {
-        let mut d = self.minx - other.minx;
-        if feq(d, 0.0) {
-            d = self.miny - other.miny;
-        }
-        if feq(d, 0.0) {
-            Ordering::Equal
-        } else if d < 0.0 {
-            Ordering::Less
-        } else {
-            Ordering::Greater
-        }
+        total_cmp(self.minx, other.minx)
+            .then_with(|| total_cmp(self.miny, other.miny))
+            .then_with(|| total_cmp(self.maxx, other.maxx))
+            .then_with(|| total_cmp(self.maxy, other.maxy))
     }
 }
 
+///Total order of f64 with -0.0 and 0.0 as equal
+#[inline]
+pub(crate) fn total_cmp(a: f64, b: f64) -> Ordering {
+    (a + 0.0).total_cmp(&(b + 0.0))
+}
+
 ///PartialOrd for MBR
 impl PartialOrd for MBR {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -508,6 +748,22 @@ impl PointDistance for MBR {
     }
 }
 
+///RTreeObject for IndexedMBR
+impl RTreeObject for IndexedMBR {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.mbr.envelope()
+    }
+}
+
+///PointDistance for IndexedMBR
+impl PointDistance for IndexedMBR {
+    fn distance_2(&self, pt: &[f64; 2]) -> f64 {
+        self.mbr.distance_2(pt)
+    }
+}
+
 ///BBox for MBR
 impl BBox for MBR {
     fn bbox(&self) -> &MBR {
@@ -515,5 +771,126 @@ impl BBox for MBR {
     }
 }
 
+///BBox for IndexedMBR
+impl BBox for IndexedMBR {
+    fn bbox(&self) -> &MBR {
+        &self.mbr
+    }
+}
+
+///Wrapper indexing any BBox in an rstar tree by its bounding box,
+///e.g. `RTree<BBoxObject<Building>>`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BBoxObject<T>(pub T);
+
+impl<T> BBoxObject<T> {
+    ///Wrapped object
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for BBoxObject<T> {
+    fn from(item: T) -> Self {
+        BBoxObject(item)
+    }
+}
+
+impl<T> ops::Deref for BBoxObject<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> ops::DerefMut for BBoxObject<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+///BBox for BBoxObject
+impl<T: BBox> BBox for BBoxObject<T> {
+    fn bbox(&self) -> &MBR {
+        self.0.bbox()
+    }
+}
+
+///RTreeObject for BBoxObject
+impl<T: BBox> RTreeObject for BBoxObject<T> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.0.bbox().envelope()
+    }
+}
+
+///PointDistance for BBoxObject
+impl<T: BBox> PointDistance for BBoxObject<T> {
+    fn distance_2(&self, pt: &[f64; 2]) -> f64 {
+        self.0.bbox().distance_2(pt)
+    }
+}
+
 #[cfg(test)]
 mod mbr_tests;
+
+#[cfg(test)]
+mod bounds_tests;
+
+#[cfg(test)]
+mod columns_tests;
+
+#[cfg(all(test, feature = "euclid"))]
+mod euclid_tests;
+
+#[cfg(test)]
+mod extent_tests;
+
+#[cfg(all(test, feature = "geo"))]
+mod geo_tests;
+
+#[cfg(test)]
+mod grid_tests;
+
+#[cfg(test)]
+mod index_tests;
+
+#[cfg(test)]
+mod irect_tests;
+
+#[cfg(test)]
+mod metric_tests;
+
+#[cfg(all(test, feature = "mint"))]
+mod mint_tests;
+
+#[cfg(test)]
+mod obb_tests;
+
+#[cfg(test)]
+mod packed_tests;
+
+#[cfg(test)]
+mod plot_tests;
+
+#[cfg(test)]
+mod quadtree_tests;
+
+#[cfg(test)]
+mod raster_tests;
+
+#[cfg(test)]
+mod ray_tests;
+
+#[cfg(test)]
+mod sfc_tests;
+
+#[cfg(test)]
+mod svg_tests;
+
+#[cfg(test)]
+mod viewport_tests;
+
+#[cfg(test)]
+mod test_util;
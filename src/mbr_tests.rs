@@ -271,8 +271,8 @@ fn test_ops2() {
     //    SECTION("intersects , distance")
     assert!(m1.intersects_xy(p[0], p[1]));
     assert!(m1.intersects_xy(p0[0], p0[1]));
-    assert!(m1.intersects_point(&p));
-    assert!(m1.intersects_point(&p0));
+    assert!(m1.intersects_point(p));
+    assert!(m1.intersects_point(p0));
 
     assert!(m00.intersects(&n00));
     let nm00 = m00.intersection(&n00);
@@ -438,3 +438,178 @@ fn test_ops2() {
         "POLYGON((0 0,0 2,2 2,2 0,0 0))".to_string()
     );
 }
+
+#[test]
+fn test_distance_bounds() {
+    let m = MBR::new(0., 0., 4., 2.);
+
+    assert_eq!(m.closest_point([1., 1.]), [1., 1.]);
+    assert_eq!(m.closest_point([-3., 1.]), [0., 1.]);
+    assert_eq!(m.closest_point([6., 5.]), [4., 2.]);
+    assert_eq!(m.farthest_point([1., 1.5]), [4., 0.]);
+    assert_eq!(m.farthest_point([6., -5.]), [0., 2.]);
+
+    //max distance
+    let p: MBR = [7., 6.].into();
+    assert_eq!(m.max_distance(&p), 7f64.hypot(6.));
+    assert_eq!(m.max_distance_square(&p), 85.);
+    assert_eq!(p.max_distance(&m), m.max_distance(&p));
+    let o = MBR::new(1., 1., 2., 5.);
+    assert_eq!(m.max_distance_dxdy(&o), (3., 5.));
+    assert_eq!(m.max_distance(&m), 4f64.hypot(2.));
+
+    //hausdorff
+    assert_eq!(m.hausdorff_distance(&m), 0.0);
+    let inner = MBR::new(1., 0.5, 2., 1.5);
+    assert_eq!(m.hausdorff_distance(&inner), 2f64.hypot(0.5));
+    assert_eq!(inner.hausdorff_distance(&m), 2f64.hypot(0.5));
+    let far = m.translate(10., 0.);
+    assert_eq!(m.hausdorff_distance(&far), 10.);
+
+    //minmax distance bounds the distance to the nearest enclosed object
+    let pt = [-1., -1.];
+    assert_eq!(m.minmax_distance_square(pt), 1. + 9.);
+    assert_eq!(m.minmax_distance([2., 5.]), 2f64.hypot(3.));
+    assert_eq!(m.minmax_distance([1., 1.]), 1f64.hypot(1.));
+    for q in [[-1., -1.], [2., 5.], [9., -3.], [1., 1.]].iter() {
+        let d = MBR::new_from_pt(*q);
+        assert!(m.distance(&d) <= m.minmax_distance(*q));
+        assert!(m.minmax_distance(*q) <= m.max_distance(&d));
+    }
+}
+
+#[test]
+fn test_tolerance() {
+    let eps = 1.0e-6;
+    let a = MBR::new(0., 0., 2., 2.);
+    let b = MBR::new(0.0000005, -0.0000005, 2.0000009, 1.9999991);
+    let c = MBR::new(2.0000008, 0.5, 3., 1.5);
+
+    assert!(!a.equals(&b));
+    assert!(a.equals_eps(&b, eps));
+    assert!(b.equals_eps(&a, eps));
+    assert!(!a.equals_eps(&b, eps / 10.));
+    assert!(a.equals_eps(&a, 0.));
+
+    //predicates agree with equality
+    assert!(!a.contains(&b));
+    assert!(a.contains_eps(&b, eps) && b.contains_eps(&a, eps));
+    assert!(a.intersects_eps(&b, eps));
+
+    assert!(a.disjoint(&c));
+    assert!(a.intersects_eps(&c, eps));
+    assert!(c.intersects_eps(&a, eps));
+    assert!(!a.disjoint_eps(&c, eps));
+    assert!(a.disjoint_eps(&c, eps / 10.));
+    assert_eq!(a.contains_eps(&c, 0.), a.contains(&c));
+    assert_eq!(a.intersects_eps(&c, 0.), a.intersects(&c));
+
+    assert!(!a.contains_point([2.0000005, 1.]));
+    assert!(a.contains_point_eps([2.0000005, 1.], eps));
+    assert!(a.contains_xy_eps(-0.0000005, -0.000001, eps));
+    assert!(!a.contains_xy_eps(-0.0000005, -0.000002, eps));
+
+    let p = MBR::new(1., 1., 1.0000004, 1.0000004);
+    assert!(!p.is_point());
+    assert!(p.is_point_eps(eps));
+    assert!(!p.is_point_eps(1.0e-7));
+    assert!(!a.is_point_eps(eps));
+}
+
+#[test]
+fn test_ordering() {
+    let a = MBR::new(0., 0., 1., 1.);
+    let b = MBR::new(0., 0., 1., 2.);
+    let c = MBR::new(0., 0., 1.0000000000001, 1.);
+
+    //maxima take part in the order
+    assert_eq!(a.cmp(&b), Ordering::Less);
+    assert_eq!(b.cmp(&a), Ordering::Greater);
+    assert_ne!(a, b);
+
+    //exact and transitive, equals keeps its tolerance
+    assert!(a.equals(&c));
+    assert_ne!(a, c);
+    assert!(a < b && b < c && a < c);
+    assert_eq!(MBR::new(-0.0, 0., 1., 1.), a);
+    assert_eq!(MBR::new(-0.0, 0., 1., 1.).cmp(&a), Ordering::Equal);
+
+    let mut vects = vec![
+        MBR::new(1., 0., 2., 2.),
+        b,
+        MBR::new(-1., 5., 0., 6.),
+        a,
+        MBR::new(0., -1., 3., 0.),
+    ];
+    vects.sort();
+    assert_eq!(
+        vects,
+        vec![MBR::new(-1., 5., 0., 6.), MBR::new(0., -1., 3., 0.), a, b, MBR::new(1., 0., 2., 2.)]
+    );
+
+    vects.sort_by(cmp_area);
+    let areas: Vec<f64> = vects.iter().map(|m| m.area()).collect();
+    assert_eq!(areas, vec![1., 1., 2., 2., 3.]);
+    assert_eq!(vects[0], MBR::new(-1., 5., 0., 6.));
+
+    vects.sort_by(cmp_centre_x);
+    let xs: Vec<f64> = vects.iter().map(|m| m.centre()[0]).collect();
+    assert_eq!(xs, vec![-0.5, 0.5, 0.5, 1.5, 1.5]);
+    assert_eq!((vects[1], vects[2]), (a, b));
+
+    //quadrants of the hilbert curve: ll, ul, ur, lr
+    let extent = MBR::new(0., 0., 4., 4.);
+    let ll = MBR::new(0., 0., 1., 1.);
+    let ul = MBR::new(0., 3., 1., 4.);
+    let ur = MBR::new(3., 3., 4., 4.);
+    let lr = MBR::new(3., 0., 4., 1.);
+    let mut vects = vec![ur, lr, ll, ul];
+    vects.sort_by(cmp_hilbert(extent));
+    assert_eq!(vects, vec![ll, ul, ur, lr]);
+}
+
+#[test]
+fn test_bbox_object() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Building {
+        name: &'static str,
+        bounds: MBR,
+    }
+    impl BBox for Building {
+        fn bbox(&self) -> &MBR {
+            &self.bounds
+        }
+    }
+    let building = |name, bounds: [f64; 4]| BBoxObject(Building { name, bounds: bounds.into() });
+
+    let mut tree = RTree::bulk_load(vec![
+        building("school", [0., 0., 10., 10.]),
+        building("library", [20., 0., 25., 8.]),
+        building("hall", [5., 30., 15., 40.]),
+    ]);
+    tree.insert(building("depot", [40., 40., 45., 45.]).into_inner().into());
+
+    let window = MBR::new(8., 5., 22., 6.);
+    let mut names: Vec<&str> = tree
+        .locate_in_envelope_intersecting(&window.envelope())
+        .map(|b| b.name)
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, vec!["library", "school"]);
+
+    let nearest = tree.nearest_neighbor(&[18., 35.]).unwrap();
+    assert_eq!(nearest.name, "hall");
+    assert_eq!(nearest.distance_2(&[18., 35.]), 9.);
+    assert_eq!(nearest.bbox(), &MBR::new(5., 30., 15., 40.));
+
+    let depot = building("depot", [40., 40., 45., 45.]);
+    assert!(tree.remove(&depot).is_some());
+    assert_eq!(tree.size(), 3);
+
+    let mut obj = building("shed", [1., 1., 2., 2.]);
+    obj.name = "barn";
+    assert_eq!(obj.into_inner().name, "barn");
+
+    let indexed = IndexedMBR { mbr: MBR::new(0., 0., 1., 1.), index: 3 };
+    assert_eq!(BBoxObject(indexed).bbox(), &indexed.mbr);
+}
@@ -0,0 +1,59 @@
+use crate::{Boxes, MBR};
+use std::cmp::Ordering;
+
+///Distance metric between boxes and points
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Metric {
+    #[default]
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+    ///Euclidean with dx and dy scaled by weights (wx, wy)
+    Weighted(f64, f64),
+}
+
+impl Metric {
+    ///Distance given absolute deltas in x and y
+    #[inline]
+    pub fn distance(&self, dx: f64, dy: f64) -> f64 {
+        match *self {
+            Metric::Euclidean => dx.hypot(dy),
+            Metric::Manhattan => dx.abs() + dy.abs(),
+            Metric::Chebyshev => dx.abs().max(dy.abs()),
+            Metric::Weighted(wx, wy) => (wx * dx).hypot(wy * dy),
+        }
+    }
+}
+
+impl MBR {
+    ///distance between two mbrs under metric
+    pub fn distance_metric(&self, other: &Self, metric: Metric) -> f64 {
+        let (dx, dy) = self.distance_dxdy(other);
+        metric.distance(dx, dy)
+    }
+
+    ///distance between mbr and point under metric
+    pub fn distance_point_metric<P: Into<[f64; 2]>>(&self, pt: P, metric: Metric) -> f64 {
+        self.distance_metric(&MBR::new_from_pt(pt.into()), metric)
+    }
+}
+
+impl Boxes {
+    ///k nearest boxes to point under metric, as (index, distance) sorted by distance
+    pub fn knn<P: Into<[f64; 2]>>(&self, pt: P, k: usize, metric: Metric) -> Vec<(usize, f64)> {
+        let pt = pt.into();
+        let mut items: Vec<(usize, f64)> = self.boxes.iter()
+            .map(|b| b.distance_point_metric(pt, metric))
+            .enumerate()
+            .collect();
+        let cmp = |a: &(usize, f64), b: &(usize, f64)| {
+            a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal).then(a.0.cmp(&b.0))
+        };
+        if k < items.len() {
+            items.select_nth_unstable_by(k, cmp);
+            items.truncate(k);
+        }
+        items.sort_by(cmp);
+        items
+    }
+}
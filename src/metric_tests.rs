@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_metrics() {
+    let a = MBR::new(0., 0., 1., 1.);
+    let b = MBR::new(4., 5., 6., 6.);
+
+    assert_eq!(a.distance_dxdy(&b), (3., 4.));
+    assert_eq!(a.distance_metric(&b, Metric::Euclidean), a.distance(&b));
+    assert_eq!(a.distance_metric(&b, Metric::default()), 5.);
+    assert_eq!(a.distance_metric(&b, Metric::Manhattan), 7.);
+    assert_eq!(a.distance_metric(&b, Metric::Chebyshev), 4.);
+    assert_eq!(a.distance_metric(&b, Metric::Weighted(1., 0.)), 3.);
+    assert_eq!(a.distance_metric(&b, Metric::Weighted(4., 3.)), 12f64.hypot(12.));
+    assert_eq!(b.distance_metric(&a, Metric::Manhattan), 7.);
+
+    let c = MBR::new(0.5, 0.5, 3., 3.);
+    for &m in [Metric::Euclidean, Metric::Manhattan, Metric::Chebyshev].iter() {
+        assert_eq!(a.distance_metric(&c, m), 0.);
+        assert_eq!(a.distance_point_metric([0.5, 0.5], m), 0.);
+    }
+    assert_eq!(a.distance_point_metric([-2., 3.], Metric::Manhattan), 4.);
+    assert_eq!(a.distance_point_metric([-2., 3.], Metric::Chebyshev), 2.);
+}
+
+#[test]
+fn test_knn() {
+    let data: Boxes = vec![
+        [0, 0, 1, 1],
+        [3, 3, 4, 4],
+        [5, 0, 6, 1],
+        [0, 5, 1, 6],
+        [-4, -4, -3, -3],
+    ]
+    .into();
+
+    let pt = [2., 2.];
+    let res = data.knn(pt, 2, Metric::Euclidean);
+    assert_eq!(res, vec![(0, 2f64.sqrt()), (1, 2f64.sqrt())]);
+
+    let res = data.knn(pt, 3, Metric::Manhattan);
+    assert_eq!(res, vec![(0, 2.), (1, 2.), (2, 4.)]);
+
+    let res = data.knn(pt, 3, Metric::Chebyshev);
+    assert_eq!(res, vec![(0, 1.), (1, 1.), (2, 3.)]);
+
+    //x heavily weighted, box 3 is nearer than box 2
+    let res = data.knn(pt, 3, Metric::Weighted(10., 1.));
+    assert_eq!(res, vec![(0, 10f64.hypot(1.)), (1, 10f64.hypot(1.)), (3, 10f64.hypot(3.))]);
+
+    let res = data.knn(pt, 10, Metric::Euclidean);
+    assert_eq!(res.len(), 5);
+    assert_eq!(res[4].0, 4);
+    assert!(data.knn(pt, 0, Metric::Euclidean).is_empty());
+}
@@ -0,0 +1,21 @@
+use super::*;
+use mint::{Point2, Vector2};
+
+#[test]
+fn test_mint() {
+    let a = Point2 { x: 3., y: 4. };
+    let b = Point2 { x: 1., y: 2. };
+    let mbr = MBR::new(1., 2., 3., 4.);
+    let corners: [Point2<f64>; 2] = mbr.into();
+    assert_eq!(corners, [b, a]);
+    assert_eq!(MBR::from(a), MBR::new_from_pt([3., 4.]));
+    assert_eq!(MBR::from(Vector2 { x: 3., y: 4. }), MBR::from(a));
+
+    assert!(mbr.contains_point(Point2 { x: 2., y: 3. }));
+    assert!(!mbr.contains_point(Vector2 { x: 0., y: 3. }));
+    let mut m = MBR::new_from_pt([0., 0.]);
+    m.expand_to_include_point(a).expand_to_include_point([-1., 0.]);
+    assert_eq!(m, MBR::new(-1., 0., 3., 4.));
+
+    assert_eq!(vec![a, b].bounds(), Some(mbr));
+}
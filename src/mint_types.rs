@@ -0,0 +1,33 @@
+use crate::{Bounds, MBR};
+use mint::{Point2, Vector2};
+
+impl From<Point2<f64>> for MBR {
+    fn from(pt: Point2<f64>) -> Self {
+        MBR::new_from_pt(pt.into())
+    }
+}
+
+impl From<Vector2<f64>> for MBR {
+    fn from(v: Vector2<f64>) -> Self {
+        MBR::new_from_pt(v.into())
+    }
+}
+
+///Lower left and upper right corners
+impl From<MBR> for [Point2<f64>; 2] {
+    fn from(mbr: MBR) -> Self {
+        [mbr.ll().into(), mbr.ur().into()]
+    }
+}
+
+impl Bounds for Point2<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
+
+impl Bounds for Vector2<f64> {
+    fn bounds(&self) -> Option<MBR> {
+        Some((*self).into())
+    }
+}
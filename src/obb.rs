@@ -0,0 +1,210 @@
+use crate::MBR;
+use std::cmp::Ordering;
+
+///Oriented bounding box: centre, half extents along its axes and the angle (radians, ccw)
+///of its first axis from the x axis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OBB {
+    pub centre: [f64; 2],
+    pub half_extents: [f64; 2],
+    pub angle: f64,
+}
+
+impl OBB {
+    ///New OBB
+    pub fn new(centre: [f64; 2], half_extents: [f64; 2], angle: f64) -> OBB {
+        OBB { centre, half_extents, angle }
+    }
+
+    ///Axis aligned OBB of mbr
+    pub fn from_mbr(mbr: &MBR) -> OBB {
+        OBB::new(mbr.centre(), [mbr.width() / 2.0, mbr.height() / 2.0], 0.0)
+    }
+
+    ///Minimum area OBB of points by rotating calipers over their convex hull,
+    ///None if there are no points
+    pub fn min_area(points: &[[f64; 2]]) -> Option<OBB> {
+        calipers(points, |a, b| a.area().total_cmp(&b.area()))
+    }
+
+    ///Minimum width OBB of points by rotating calipers over their convex hull,
+    ///the width being the smaller extent; None if there are no points
+    pub fn min_width(points: &[[f64; 2]]) -> Option<OBB> {
+        calipers(points, |a, b| {
+            a.width().total_cmp(&b.width()).then(a.area().total_cmp(&b.area()))
+        })
+    }
+
+    ///Unit axes along the first and second half extents
+    pub fn axes(&self) -> [[f64; 2]; 2] {
+        let (s, c) = self.angle.sin_cos();
+        [[c, s], [-s, c]]
+    }
+
+    ///Area
+    pub fn area(&self) -> f64 {
+        4.0 * self.half_extents[0] * self.half_extents[1]
+    }
+
+    ///Smaller of the two extents
+    pub fn width(&self) -> f64 {
+        2.0 * self.half_extents[0].min(self.half_extents[1])
+    }
+
+    ///Corners in counter clockwise order
+    pub fn corners(&self) -> [[f64; 2]; 4] {
+        let [u, v] = self.axes();
+        let [hx, hy] = self.half_extents;
+        let c = self.centre;
+        let at = |su: f64, sv: f64| {
+            [c[0] + su * hx * u[0] + sv * hy * v[0], c[1] + su * hx * u[1] + sv * hy * v[1]]
+        };
+        [at(-1.0, -1.0), at(1.0, -1.0), at(1.0, 1.0), at(-1.0, 1.0)]
+    }
+
+    ///OBB as a closed polygon array
+    pub fn as_poly_array(&self) -> Vec<[f64; 2]> {
+        let corners = self.corners();
+        let mut poly = corners.to_vec();
+        poly.push(corners[0]);
+        poly
+    }
+
+    ///Axis aligned bounding box
+    pub fn mbr(&self) -> MBR {
+        let [u, v] = self.axes();
+        let [hx, hy] = self.half_extents;
+        let dx = hx * u[0].abs() + hy * v[0].abs();
+        let dy = hx * u[1].abs() + hy * v[1].abs();
+        let c = self.centre;
+        MBR::new_raw(c[0] - dx, c[1] - dy, c[0] + dx, c[1] + dy)
+    }
+
+    ///Contains point, boundaries included
+    pub fn contains_point(&self, pt: [f64; 2]) -> bool {
+        let [u, v] = self.axes();
+        let d = [pt[0] - self.centre[0], pt[1] - self.centre[1]];
+        dot(d, u).abs() <= self.half_extents[0] && dot(d, v).abs() <= self.half_extents[1]
+    }
+
+    ///Separating axis test, touching boxes intersect
+    pub fn intersects(&self, other: &OBB) -> bool {
+        let d = [other.centre[0] - self.centre[0], other.centre[1] - self.centre[1]];
+        let axes = self.axes();
+        let other_axes = other.axes();
+        axes.iter().chain(other_axes.iter()).all(|&axis| {
+            dot(d, axis).abs() <= self.radius(axis) + other.radius(axis)
+        })
+    }
+
+    ///Separating axis test against mbr
+    pub fn intersects_mbr(&self, mbr: &MBR) -> bool {
+        self.intersects(&OBB::from_mbr(mbr))
+    }
+
+    ///Half length of the projection onto axis
+    fn radius(&self, axis: [f64; 2]) -> f64 {
+        let [u, v] = self.axes();
+        self.half_extents[0] * dot(u, axis).abs() + self.half_extents[1] * dot(v, axis).abs()
+    }
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+///Convex hull in counter clockwise order without collinear points (monotone chain)
+fn convex_hull(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    let mut pts = points.to_vec();
+    pts.sort_by(|a, b| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])));
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+    let mut hull: Vec<[f64; 2]> = Vec::with_capacity(2 * pts.len());
+    for pass in 0..2 {
+        let start = hull.len();
+        for &p in pts.iter() {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        if pass == 0 {
+            pts.reverse();
+        }
+    }
+    hull
+}
+
+///Rotating calipers over the hull edges keeping the best box by cmp
+fn calipers<F>(points: &[[f64; 2]], cmp: F) -> Option<OBB>
+    where
+        F: Fn(&OBB, &OBB) -> Ordering,
+{
+    let hull = convex_hull(points);
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => return Some(OBB::new(hull[0], [0.0, 0.0], 0.0)),
+        2 => {
+            let (a, b) = (hull[0], hull[1]);
+            let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+            let centre = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+            return Some(OBB::new(centre, [dx.hypot(dy) / 2.0, 0.0], dy.atan2(dx)));
+        }
+        _ => {}
+    }
+
+    let mut best: Option<OBB> = None;
+    //callipers: furthest along the edge, furthest from the edge, furthest back along the edge
+    let (mut right, mut top, mut left) = (1, 1, 1);
+    for i in 0..n {
+        let (p, q) = (hull[i], hull[(i + 1) % n]);
+        let (dx, dy) = (q[0] - p[0], q[1] - p[1]);
+        let len = dx.hypot(dy);
+        let u = [dx / len, dy / len];
+        let v = [-u[1], u[0]];
+        let next = |k: usize| (k + 1) % n;
+
+        while dot(hull[next(right)], u) > dot(hull[right], u) {
+            right = next(right);
+        }
+        if i == 0 {
+            top = right;
+        }
+        while dot(hull[next(top)], v) > dot(hull[top], v) {
+            top = next(top);
+        }
+        if i == 0 {
+            left = top;
+        }
+        while dot(hull[next(left)], u) < dot(hull[left], u) {
+            left = next(left);
+        }
+
+        let (a0, a1) = (dot(hull[left], u), dot(hull[right], u));
+        let (b0, b1) = (dot(p, v), dot(hull[top], v));
+        let (a, b) = ((a0 + a1) / 2.0, (b0 + b1) / 2.0);
+        let obb = OBB::new(
+            [a * u[0] + b * v[0], a * u[1] + b * v[1]],
+            [(a1 - a0) / 2.0, (b1 - b0) / 2.0],
+            u[1].atan2(u[0]),
+        );
+        let better = match &best {
+            Some(b) => cmp(&obb, b) == Ordering::Less,
+            None => true,
+        };
+        if better {
+            best = Some(obb);
+        }
+    }
+    best
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::test_util::lcg;
+use std::f64::consts::{FRAC_PI_4, FRAC_PI_6};
+
+fn close(a: [f64; 2], b: [f64; 2]) -> bool {
+    (a[0] - b[0]).abs() < 1e-9 && (a[1] - b[1]).abs() < 1e-9
+}
+
+#[test]
+fn test_obb() {
+    let obb = OBB::new([1., 1.], [2., 1.], FRAC_PI_4);
+    assert_eq!(obb.area(), 8.);
+    assert_eq!(obb.width(), 2.);
+    let s = 0.5f64.sqrt();
+    let mbr = obb.mbr();
+    assert!(close(mbr.ll(), [1. - 3. * s, 1. - 3. * s]));
+    assert!(close(mbr.ur(), [1. + 3. * s, 1. + 3. * s]));
+
+    let corners = obb.corners();
+    assert!(close(corners[0], [1. - s, 1. - 3. * s]));
+    assert!(close(corners[2], [1. + s, 1. + 3. * s]));
+    let poly = obb.as_poly_array();
+    assert_eq!((poly.len(), poly[0]), (5, poly[4]));
+
+    assert!(obb.contains_point([1., 1.]));
+    assert!(obb.contains_point([2., 2.]));
+    assert!(!obb.contains_point([1. + 2. * s, 1. - 2. * s]));
+
+    let aligned = OBB::from_mbr(&MBR::new(0., 0., 4., 2.));
+    assert_eq!(aligned, OBB::new([2., 1.], [2., 1.], 0.));
+    assert_eq!(aligned.mbr(), MBR::new(0., 0., 4., 2.));
+}
+
+#[test]
+fn test_obb_sat() {
+    let diamond = OBB::new([0., 0.], [1., 1.], FRAC_PI_4);
+    //the corner region of the aabb of the diamond is outside it
+    assert!(diamond.mbr().intersects(&MBR::new(0.8, 0.8, 2., 2.)));
+    assert!(!diamond.intersects_mbr(&MBR::new(0.8, 0.8, 2., 2.)));
+    assert!(diamond.intersects_mbr(&MBR::new(0.6, 0.6, 2., 2.)));
+    assert!(diamond.intersects_mbr(&MBR::new(-0.1, -0.1, 0.1, 0.1)));
+    //touching at a corner
+    assert!(diamond.intersects_mbr(&MBR::new(2f64.sqrt(), -1., 3., 1.)));
+
+    let other = OBB::new([2.5, 0.], [1., 0.5], -FRAC_PI_6);
+    assert!(!diamond.intersects(&other));
+    assert!(diamond.intersects(&OBB::new([2., 0.], [1., 0.5], -FRAC_PI_6)));
+    assert!(diamond.intersects(&diamond));
+}
+
+#[test]
+fn test_obb_calipers() {
+    //rotated 3 x 1 rectangle with interior points
+    let box_ = OBB::new([5., -2.], [1.5, 0.5], FRAC_PI_6);
+    let mut pts = box_.corners().to_vec();
+    pts.extend_from_slice(&[[5., -2.], [5.2, -1.9], [4.9, -2.1]]);
+    let min = OBB::min_area(&pts).unwrap();
+    assert!((min.area() - 3.).abs() < 1e-9);
+    let mut a = min.corners().to_vec();
+    let mut b = box_.corners().to_vec();
+    let key = |p: &[f64; 2]| (p[0] * 1e6).round() as i64;
+    a.sort_by_key(key);
+    b.sort_by_key(key);
+    assert!(a.iter().zip(b.iter()).all(|(p, q)| close(*p, *q)));
+    let mut loose = min;
+    loose.half_extents = [min.half_extents[0] + 1e-9, min.half_extents[1] + 1e-9];
+    assert!(pts.iter().all(|&p| loose.contains_point(p)));
+
+    //a right triangle: min width is the height to the hypotenuse, min area is flush with a leg
+    let tri = [[0., 0.], [4., 0.], [0., 3.]];
+    let w = OBB::min_width(&tri).unwrap();
+    assert!((w.width() - 2.4).abs() < 1e-9);
+    let m = OBB::min_area(&tri).unwrap();
+    assert!((m.area() - 12.).abs() < 1e-9);
+    for &p in tri.iter() {
+        assert!(m.mbr().contains_point(p) && w.mbr().contains_point(p));
+    }
+
+    assert_eq!(OBB::min_area(&[]), None);
+    assert_eq!(OBB::min_area(&[[1., 2.], [1., 2.]]), Some(OBB::new([1., 2.], [0., 0.], 0.)));
+    let seg = OBB::min_area(&[[0., 0.], [1., 1.], [2., 2.]]).unwrap();
+    assert!(close(seg.centre, [1., 1.]));
+    assert!((seg.half_extents[0] - 2f64.sqrt()).abs() < 1e-12 && seg.half_extents[1] == 0.);
+    assert!((seg.angle - FRAC_PI_4).abs() < 1e-12);
+}
+
+#[test]
+fn test_obb_calipers_brute_force() {
+    let mut rand = lcg(7);
+    for _ in 0..20 {
+        let pts: Vec<[f64; 2]> = (0..40).map(|_| [rand() * 10., rand() * 4. + rand()]).collect();
+        //the optimum is flush with a hull edge, every pair of points includes them
+        let (mut area, mut width) = (f64::INFINITY, f64::INFINITY);
+        for p in &pts {
+            for q in &pts {
+                let (dx, dy) = (q[0] - p[0], q[1] - p[1]);
+                let len = dx.hypot(dy);
+                if len == 0. {
+                    continue;
+                }
+                let (u, v) = ([dx / len, dy / len], [-dy / len, dx / len]);
+                let proj = |axis: [f64; 2]| {
+                    let d: Vec<f64> = pts.iter().map(|r| r[0] * axis[0] + r[1] * axis[1]).collect();
+                    let max = d.iter().cloned().fold(f64::MIN, f64::max);
+                    let min = d.iter().cloned().fold(f64::MAX, f64::min);
+                    max - min
+                };
+                let (a, b) = (proj(u), proj(v));
+                area = area.min(a * b);
+                width = width.min(a.min(b));
+            }
+        }
+        assert!((OBB::min_area(&pts).unwrap().area() - area).abs() < 1e-9);
+        assert!((OBB::min_width(&pts).unwrap().width() - width).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{total_cmp, MBR};
+use std::cmp::Ordering;
+
+///Order of hilbert curve used by `cmp_hilbert`
+const HILBERT_ORDER: u32 = 16;
+
+///Compares mbrs by area, ties broken by `Ord`
+pub fn cmp_area(a: &MBR, b: &MBR) -> Ordering {
+    total_cmp(a.area(), b.area()).then_with(|| a.cmp(b))
+}
+
+///Compares mbrs by x then y of centre, ties broken by `Ord`
+pub fn cmp_centre_x(a: &MBR, b: &MBR) -> Ordering {
+    let (ca, cb) = (a.centre(), b.centre());
+    total_cmp(ca[0], cb[0])
+        .then_with(|| total_cmp(ca[1], cb[1]))
+        .then_with(|| a.cmp(b))
+}
+
+///Comparator by hilbert index of centre within extent, ties broken by `Ord`
+///e.g. `boxes.sort_by(cmp_hilbert(extent))`
+pub fn cmp_hilbert(extent: MBR) -> impl Fn(&MBR, &MBR) -> Ordering {
+    move |a, b| {
+        a.hilbert_key(&extent, HILBERT_ORDER)
+            .cmp(&b.hilbert_key(&extent, HILBERT_ORDER))
+            .then_with(|| a.cmp(b))
+    }
+}
@@ -0,0 +1,266 @@
+use crate::heap::MinDist;
+use crate::{Boxes, MBR};
+use std::collections::BinaryHeap;
+use std::convert::{TryFrom, TryInto};
+
+const MAGIC: &[u8; 4] = b"BBPR";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 16;
+const BOX_SIZE: usize = 32;
+const INDEX_SIZE: usize = 4;
+const HILBERT_ORDER: u32 = 16;
+
+///Packed static Hilbert R-tree (flatbush layout).
+///
+///Boxes are sorted by the hilbert key of their centres and packed bottom up
+///into nodes of node_size children. The tree lives in a single byte buffer:
+///a 16 byte header (magic `BBPR`, version, node size, item count), the node boxes
+///as little endian f64 `[minx, miny, maxx, maxy]`, and the u32 child (or item) index
+///of each node. The buffer can be written out and queried in place from any
+///`AsRef<[u8]>` (e.g. a memory map) without a rebuild.
+#[derive(Clone, Debug)]
+pub struct PackedRTree<D = Vec<u8>> {
+    data: D,
+    node_size: usize,
+    num_items: usize,
+    level_bounds: Vec<usize>,
+}
+
+impl PackedRTree<Vec<u8>> {
+    ///Builds packed tree from boxes, node_size is clamped to [2, 65535]
+    pub fn new(boxes: &Boxes, node_size: usize) -> PackedRTree<Vec<u8>> {
+        let node_size = node_size.max(2).min(u16::MAX as usize);
+        let num_items = boxes.boxes.len();
+        let level_bounds =
+            level_bounds(num_items, node_size).expect("too many boxes for packed tree");
+        let num_nodes = *level_bounds.last().unwrap();
+        assert!(num_nodes <= u32::MAX as usize, "too many boxes for packed tree");
+
+        let mut nodes: Vec<MBR> = Vec::with_capacity(num_nodes);
+        let mut indices: Vec<u32> = Vec::with_capacity(num_nodes);
+
+        if let Some(extent) = boxes.extent() {
+            let mut order: Vec<usize> = (0..num_items).collect();
+            order.sort_by_cached_key(|&i| boxes[i].hilbert_key(&extent, HILBERT_ORDER));
+            for i in order {
+                nodes.push(boxes[i]);
+                indices.push(i as u32);
+            }
+        }
+
+        //pack parents level by level, each parent indexes its first child
+        let mut pos = 0;
+        for &end in &level_bounds[..level_bounds.len() - 1] {
+            while pos < end {
+                let first = pos;
+                let mut node = nodes[pos];
+                pos += 1;
+                while pos < end && pos - first < node_size {
+                    node.expand_to_include(&nodes[pos]);
+                    pos += 1;
+                }
+                nodes.push(node);
+                indices.push(first as u32);
+            }
+        }
+
+        let mut data = Vec::with_capacity(HEADER_SIZE + num_nodes * (BOX_SIZE + INDEX_SIZE));
+        data.extend_from_slice(MAGIC);
+        data.push(VERSION);
+        data.push(0);
+        data.extend_from_slice(&(node_size as u16).to_le_bytes());
+        data.extend_from_slice(&(num_items as u64).to_le_bytes());
+        for node in &nodes {
+            for v in node.as_array().iter() {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        for i in &indices {
+            data.extend_from_slice(&i.to_le_bytes());
+        }
+
+        PackedRTree { data, node_size, num_items, level_bounds }
+    }
+}
+
+impl<D: AsRef<[u8]>> PackedRTree<D> {
+    ///Packed tree over a buffer written by `as_bytes`.
+    ///The header and the index of every node are validated, so a corrupt buffer
+    ///is an error rather than a panic when queried.
+    pub fn from_bytes(data: D) -> Result<PackedRTree<D>, &'static str> {
+        let bytes = data.as_ref();
+        if bytes.len() < HEADER_SIZE || &bytes[..4] != MAGIC {
+            return Err("not a packed rtree buffer");
+        }
+        if bytes[4] != VERSION {
+            return Err("unsupported packed rtree version");
+        }
+        let node_size = u16::from_le_bytes(bytes[6..8].try_into().unwrap()) as usize;
+        let num_items = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        if node_size < 2 {
+            return Err("invalid packed rtree node size");
+        }
+        let num_items = usize::try_from(num_items).map_err(|_| "packed rtree too large")?;
+        let level_bounds = level_bounds(num_items, node_size).ok_or("packed rtree too large")?;
+        let num_nodes = *level_bounds.last().unwrap();
+        let size = num_nodes
+            .checked_mul(BOX_SIZE + INDEX_SIZE)
+            .and_then(|n| n.checked_add(HEADER_SIZE))
+            .ok_or("packed rtree too large")?;
+        if bytes.len() != size || num_nodes > u32::MAX as usize {
+            return Err("packed rtree buffer size mismatch");
+        }
+        let tree = PackedRTree { data, node_size, num_items, level_bounds };
+        tree.validate_indices()?;
+        Ok(tree)
+    }
+
+    ///Underlying buffer
+    pub fn as_bytes(&self) -> &[u8] {
+        self.data.as_ref()
+    }
+
+    ///Number of indexed boxes
+    pub fn len(&self) -> usize {
+        self.num_items
+    }
+
+    ///Checks if tree has no boxes
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
+    ///Node size
+    pub fn node_size(&self) -> usize {
+        self.node_size
+    }
+
+    ///Extent of indexed boxes, None if empty
+    pub fn extent(&self) -> Option<MBR> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.node_box(self.num_nodes() - 1))
+    }
+
+    ///Indices of boxes that intersect window
+    pub fn search(&self, window: &MBR) -> Vec<usize> {
+        let mut results = vec![];
+        if self.is_empty() {
+            return results;
+        }
+        let mut queue = vec![self.num_nodes() - 1];
+        while let Some(node) = queue.pop() {
+            let end = (node + self.node_size).min(self.upper_bound(node));
+            for pos in node..end {
+                if !window.intersects(&self.node_box(pos)) {
+                    continue;
+                }
+                if node < self.num_items {
+                    results.push(self.node_index(pos));
+                } else {
+                    queue.push(self.node_index(pos));
+                }
+            }
+        }
+        results
+    }
+
+    ///k nearest boxes to point as (index, distance) sorted by distance
+    pub fn neighbors(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        let mut results = vec![];
+        if self.is_empty() || k == 0 {
+            return results;
+        }
+        let query = MBR::new_from_pt(pt);
+        //queue of (is leaf, pos), leaves before nodes at equal distance
+        let mut heap = BinaryHeap::new();
+        let mut node = self.num_nodes() - 1;
+        loop {
+            let end = (node + self.node_size).min(self.upper_bound(node));
+            let leaf = node < self.num_items;
+            for pos in node..end {
+                let dist = self.node_box(pos).distance_square(&query);
+                heap.push(MinDist::new(dist, (leaf, pos)));
+            }
+            while matches!(heap.peek(), Some(n) if n.item.0) {
+                let pos = heap.pop().unwrap().item.1;
+                let dist = self.node_box(pos).distance(&query);
+                results.push((self.node_index(pos), dist));
+                if results.len() == k {
+                    return results;
+                }
+            }
+            match heap.pop() {
+                Some(n) => node = self.node_index(n.item.1),
+                None => return results,
+            }
+        }
+    }
+
+    ///Checks that leaves index items and each parent indexes the first of its
+    ///node_size children on the level below, as laid out by `new`
+    fn validate_indices(&self) -> Result<(), &'static str> {
+        for pos in 0..self.num_items {
+            if self.node_index(pos) >= self.num_items {
+                return Err("packed rtree item index out of range");
+            }
+        }
+        let mut child_start = 0;
+        for level in self.level_bounds.windows(2) {
+            let (start, end) = (level[0], level[1]);
+            for (k, pos) in (start..end).enumerate() {
+                if self.node_index(pos) != child_start + k * self.node_size {
+                    return Err("packed rtree node index out of place");
+                }
+            }
+            child_start = start;
+        }
+        Ok(())
+    }
+
+    fn num_nodes(&self) -> usize {
+        *self.level_bounds.last().unwrap()
+    }
+
+    ///end of the level containing node
+    fn upper_bound(&self, node: usize) -> usize {
+        let i = self.level_bounds.partition_point(|&b| b <= node);
+        self.level_bounds[i]
+    }
+
+    fn node_box(&self, pos: usize) -> MBR {
+        let bytes = self.data.as_ref();
+        let at = HEADER_SIZE + pos * BOX_SIZE;
+        let v = |i: usize| {
+            let at = at + i * 8;
+            f64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+        };
+        MBR::new_raw(v(0), v(1), v(2), v(3))
+    }
+
+    fn node_index(&self, pos: usize) -> usize {
+        let bytes = self.data.as_ref();
+        let at = HEADER_SIZE + self.num_nodes() * BOX_SIZE + pos * INDEX_SIZE;
+        u32::from_le_bytes(bytes[at..at + INDEX_SIZE].try_into().unwrap()) as usize
+    }
+}
+
+///Cumulative node count at the end of each level, leaves first,
+///None if the count overflows
+fn level_bounds(num_items: usize, node_size: usize) -> Option<Vec<usize>> {
+    let mut bounds = vec![num_items];
+    if num_items == 0 {
+        return Some(bounds);
+    }
+    let (mut count, mut total) = (num_items, num_items);
+    loop {
+        count = count / node_size + (count % node_size != 0) as usize;
+        total = total.checked_add(count)?;
+        bounds.push(total);
+        if count == 1 {
+            break;
+        }
+    }
+    Some(bounds)
+}
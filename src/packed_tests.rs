@@ -0,0 +1,113 @@
+use super::*;
+use crate::test_util::random_boxes;
+
+#[test]
+fn test_packed_search() {
+    let data = random_boxes(1000, 42, 5.);
+    for &node_size in [2, 4, 16, 100].iter() {
+        let tree = PackedRTree::new(&data, node_size);
+        assert_eq!((tree.len(), tree.node_size()), (1000, node_size));
+        assert_eq!(tree.extent(), data.extent());
+
+        for &window in [
+            MBR::new(10., 10., 20., 20.),
+            MBR::new(50., 0., 51., 100.),
+            MBR::new(-10., -10., 200., 200.),
+            MBR::new(-10., -10., -5., -5.),
+        ]
+        .iter()
+        {
+            let mut found = tree.search(&window);
+            found.sort_unstable();
+            let expects: Vec<usize> = (0..data.boxes.len())
+                .filter(|&i| data[i].intersects(&window))
+                .collect();
+            assert_eq!(found, expects);
+        }
+    }
+}
+
+#[test]
+fn test_packed_neighbors() {
+    let data = random_boxes(500, 42, 5.);
+    let tree = PackedRTree::new(&data, 8);
+    for &pt in [[50., 50.], [-20., 130.], [3., 97.]].iter() {
+        let found = tree.neighbors(pt, 10);
+        let expects = data.knn(pt, 10, Metric::Euclidean);
+        let dists = |v: &[(usize, f64)]| v.iter().map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(dists(&found), dists(&expects));
+        for &(i, d) in &found {
+            assert_eq!(data[i].distance(&MBR::new_from_pt(pt)), d);
+        }
+    }
+    assert_eq!(tree.neighbors([0., 0.], 1000).len(), 500);
+    assert!(tree.neighbors([0., 0.], 0).is_empty());
+}
+
+#[test]
+fn test_packed_bytes() {
+    let data = random_boxes(300, 42, 5.);
+    let tree = PackedRTree::new(&data, 16);
+    let bytes = tree.as_bytes().to_vec();
+    assert_eq!(&bytes[..4], b"BBPR");
+
+    let view = PackedRTree::from_bytes(&bytes[..]).unwrap();
+    let window = MBR::new(20., 30., 60., 45.);
+    assert_eq!(view.search(&window), tree.search(&window));
+    assert_eq!(view.neighbors([1., 2.], 5), tree.neighbors([1., 2.], 5));
+    assert_eq!((view.len(), view.node_size()), (300, 16));
+
+    let owned = PackedRTree::from_bytes(bytes.clone()).unwrap();
+    assert_eq!(owned.search(&window), tree.search(&window));
+
+    assert!(PackedRTree::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(PackedRTree::from_bytes(&b"nope"[..]).is_err());
+    let mut bad = bytes.clone();
+    bad[4] = 99;
+    assert!(PackedRTree::from_bytes(bad).is_err());
+}
+
+#[test]
+fn test_packed_corrupt() {
+    let tree = PackedRTree::new(&random_boxes(300, 42, 5.), 16);
+    let bytes = tree.as_bytes().to_vec();
+    let num_nodes = (bytes.len() - 16) / 36;
+    let index_at = |pos: usize| 16 + num_nodes * 32 + pos * 4;
+
+    let mut huge = bytes.clone();
+    huge[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(PackedRTree::from_bytes(huge).is_err());
+
+    let mut small_node = bytes.clone();
+    small_node[6..8].copy_from_slice(&1u16.to_le_bytes());
+    assert!(PackedRTree::from_bytes(small_node).is_err());
+
+    //item index past the items
+    let mut bad_item = bytes.clone();
+    bad_item[index_at(5)..index_at(5) + 4].copy_from_slice(&300u32.to_le_bytes());
+    assert!(PackedRTree::from_bytes(bad_item).is_err());
+
+    //root pointing past the nodes, and a parent pointing at the wrong children
+    for &(pos, index) in [(num_nodes - 1, u32::MAX), (300, 17), (num_nodes - 1, 0)].iter() {
+        let mut bad = bytes.clone();
+        bad[index_at(pos)..index_at(pos) + 4].copy_from_slice(&index.to_le_bytes());
+        assert!(PackedRTree::from_bytes(bad).is_err());
+    }
+    assert!(PackedRTree::from_bytes(bytes).is_ok());
+}
+
+#[test]
+fn test_packed_small() {
+    let empty = PackedRTree::new(&Boxes { boxes: vec![] }, 16);
+    assert!(empty.is_empty());
+    assert_eq!(empty.extent(), None);
+    assert!(empty.search(&MBR::new(0., 0., 1., 1.)).is_empty());
+    assert!(empty.neighbors([0., 0.], 3).is_empty());
+    let view = PackedRTree::from_bytes(empty.as_bytes()).unwrap();
+    assert!(view.is_empty());
+
+    let one = PackedRTree::new(&vec![[1, 1, 2, 2]].into(), 16);
+    assert_eq!(one.search(&MBR::new(0., 0., 1., 1.)), vec![0]);
+    assert!(one.search(&MBR::new(3., 3., 4., 4.)).is_empty());
+    assert_eq!(one.neighbors([5., 2.], 3), vec![(0, 3.)]);
+}
@@ -0,0 +1,127 @@
+use crate::{Boxes, MBR};
+use std::fmt::{Display, Error, Formatter};
+
+///ASCII plot of boxes and points on a character grid.
+///
+///Boxes are drawn with `+`, `-` and `|` borders and their label at the centre,
+///cells covered by more than one box are marked `#`. Display prints the grid
+///(y axis up) followed by a legend of labels and their boxes.
+#[derive(Clone, Debug)]
+pub struct Plot {
+    width: usize,
+    height: usize,
+    shapes: Vec<(MBR, char)>,
+}
+
+impl Plot {
+    ///New plot of width x height characters (at least 2 x 2)
+    pub fn new(width: usize, height: usize) -> Plot {
+        Plot { width: width.max(2), height: height.max(2), shapes: vec![] }
+    }
+
+    ///Adds box labeled by the next letter: a, b, c, ...
+    pub fn add(&mut self, mbr: &MBR) -> &mut Self {
+        let label = (b'a' + (self.shapes.len() % 26) as u8) as char;
+        self.add_labeled(mbr, label)
+    }
+
+    ///Adds box with label
+    pub fn add_labeled(&mut self, mbr: &MBR, label: char) -> &mut Self {
+        self.shapes.push((*mbr, label));
+        self
+    }
+
+    ///Adds point with label
+    pub fn add_point(&mut self, pt: [f64; 2], label: char) -> &mut Self {
+        self.add_labeled(&MBR::new_from_pt(pt), label)
+    }
+
+    ///Adds boxes labeled by index in base 36: 0-9, a-z
+    pub fn add_boxes(&mut self, boxes: &Boxes) -> &mut Self {
+        for (i, mbr) in boxes.boxes.iter().enumerate() {
+            let label = std::char::from_digit((i % 36) as u32, 36).unwrap();
+            self.add_labeled(mbr, label);
+        }
+        self
+    }
+
+    ///Character grid, rows from top to bottom; empty boxes are not drawn
+    pub fn grid(&self) -> Vec<Vec<char>> {
+        let (w, h) = (self.width, self.height);
+        let mut grid = vec![vec![' '; w]; h];
+        let shapes: Vec<&(MBR, char)> = self.shapes.iter().filter(|s| !s.0.is_empty()).collect();
+        let mut extent = match shapes.first() {
+            Some(s) => s.0,
+            None => return grid,
+        };
+        for s in &shapes[1..] {
+            extent.expand_to_include(&s.0);
+        }
+        let col = |x: f64| scale(x, extent.minx, extent.width(), w);
+        let row = |y: f64| h - 1 - scale(y, extent.miny, extent.height(), h);
+        let cells = |mbr: &MBR| (col(mbr.minx), col(mbr.maxx), row(mbr.maxy), row(mbr.miny));
+
+        let mut cover = vec![vec![0usize; w]; h];
+        for (mbr, _) in shapes.iter().copied() {
+            let (c0, c1, r0, r1) = cells(mbr);
+            for line in &mut cover[r0..=r1] {
+                for n in &mut line[c0..=c1] {
+                    *n += 1;
+                }
+            }
+        }
+        for (line, counts) in grid.iter_mut().zip(cover.iter()) {
+            for (c, &n) in line.iter_mut().zip(counts.iter()) {
+                if n > 1 {
+                    *c = '#';
+                }
+            }
+        }
+
+        for (mbr, _) in shapes.iter().copied().filter(|s| !s.0.is_point()) {
+            let (c0, c1, r0, r1) = cells(mbr);
+            for r in [r0, r1].iter().copied() {
+                for ch in &mut grid[r][c0..=c1] {
+                    *ch = if *ch == '|' || *ch == '+' { '+' } else { '-' };
+                }
+            }
+            for c in [c0, c1].iter().copied() {
+                for line in &mut grid[r0..=r1] {
+                    line[c] = if line[c] == '-' || line[c] == '+' { '+' } else { '|' };
+                }
+            }
+            for &(r, c) in [(r0, c0), (r0, c1), (r1, c0), (r1, c1)].iter() {
+                grid[r][c] = '+';
+            }
+        }
+
+        for (mbr, label) in shapes.iter().copied() {
+            let c = mbr.centre();
+            grid[row(c[1])][col(c[0])] = *label;
+        }
+        grid
+    }
+}
+
+///Display prints the grid and a legend
+impl Display for Plot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        for line in self.grid() {
+            let line: String = line.into_iter().collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        for (mbr, label) in &self.shapes {
+            writeln!(f, "{}: {}", label, mbr)?;
+        }
+        Ok(())
+    }
+}
+
+///Cell of v along an axis of n cells spanning [min, min + size]
+fn scale(v: f64, min: f64, size: f64, n: usize) -> usize {
+    if size <= 0.0 {
+        return (n - 1) / 2;
+    }
+    let i = ((v - min) / size * (n - 1) as f64).round();
+    (i.max(0.0) as usize).min(n - 1)
+}
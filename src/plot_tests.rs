@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn test_plot() {
+    let a = MBR::new(0., 0., 4., 4.);
+    let b = MBR::new(2., 2., 8., 6.);
+    let mut plot = Plot::new(9, 7);
+    plot.add(&a).add(&b).add_point([8., 0.], '*');
+    let grid: Vec<String> = plot.grid().into_iter().map(|r| r.into_iter().collect()).collect();
+    assert_eq!(
+        grid,
+        vec![
+            "  +-----+",
+            "  |     |",
+            "+-+-+b  |",
+            "| |#|   |",
+            "| a-+---+",
+            "|   |    ",
+            "+---+   *",
+        ]
+    );
+
+    let text = plot.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 10);
+    assert_eq!(lines[4], "| a-+---+");
+    assert_eq!(lines[5], "|   |");
+    assert_eq!(lines[7], format!("a: {}", a));
+    assert_eq!(lines[9], format!("*: {}", MBR::new_from_pt([8., 0.])));
+}
+
+#[test]
+fn test_plot_boxes() {
+    let data: Boxes = vec![[0, 0, 2, 2], [0, 0, 2, 2]].into();
+    let mut plot = Plot::new(5, 3);
+    plot.add_boxes(&data);
+    let grid: Vec<String> = plot.grid().into_iter().map(|r| r.into_iter().collect()).collect();
+    assert_eq!(grid, vec!["+---+", "|#1#|", "+---+"]);
+
+    let mut plot = Plot::new(5, 3);
+    plot.add_point([1., 1.], 'p');
+    assert_eq!(plot.to_string(), "\n  p\n\np: POLYGON((1 1,1 1,1 1,1 1,1 1))\n");
+    assert_eq!(Plot::new(0, 0).grid(), vec![vec![' '; 2]; 2]);
+
+    //empty boxes are skipped
+    let mut plot = Plot::new(5, 3);
+    plot.add(&MBR::new_empty());
+    assert_eq!(plot.grid(), vec![vec![' '; 5]; 3]);
+    let empty: MBR = Vec::<[f64; 2]>::new().into_iter().collect();
+    plot.add(&empty).add_labeled(&MBR::new(0., 0., 2., 2.), 'c');
+    let grid: Vec<String> = plot.grid().into_iter().map(|r| r.into_iter().collect()).collect();
+    assert_eq!(grid, vec!["+---+", "| c |", "+---+"]);
+}
@@ -0,0 +1,251 @@
+use crate::heap::MinDist;
+use crate::MBR;
+use std::collections::{BinaryHeap, HashMap};
+
+///Quadtree configuration
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QuadTreeConfig {
+    ///Maximum depth, the root is at depth 0
+    pub max_depth: usize,
+    ///Number of boxes a node holds before it splits
+    pub capacity: usize,
+    ///Loose factor (>= 1) by which node bounds are scaled about their centre
+    pub looseness: f64,
+}
+
+impl Default for QuadTreeConfig {
+    fn default() -> Self {
+        QuadTreeConfig { max_depth: 8, capacity: 8, looseness: 2.0 }
+    }
+}
+
+///Loose quadtree over boxes keyed by id.
+///
+///A box is stored in the deepest node whose loose bounds contain it, so boxes can move
+///within the slack of their node without being reinserted. Boxes outside the
+///root bounds are kept at the root.
+#[derive(Clone, Debug)]
+pub struct QuadTree {
+    config: QuadTreeConfig,
+    nodes: Vec<Node>,
+    locations: HashMap<usize, usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    bounds: MBR,
+    loose: MBR,
+    depth: usize,
+    children: Option<[usize; 4]>,
+    items: Vec<(usize, MBR)>,
+}
+
+impl Node {
+    fn new(bounds: MBR, depth: usize, looseness: f64) -> Node {
+        let mut loose = bounds;
+        let f = (looseness.max(1.0) - 1.0) / 2.0;
+        loose.expand_by_delta(bounds.width() * f, bounds.height() * f);
+        Node { bounds, loose, depth, children: None, items: vec![] }
+    }
+}
+
+impl QuadTree {
+    ///New quadtree over bounds
+    pub fn new(bounds: MBR, config: QuadTreeConfig) -> QuadTree {
+        QuadTree {
+            config,
+            nodes: vec![Node::new(bounds, 0, config.looseness)],
+            locations: HashMap::new(),
+        }
+    }
+
+    ///Configuration
+    pub fn config(&self) -> QuadTreeConfig {
+        self.config
+    }
+
+    ///Bounds of root
+    pub fn bounds(&self) -> MBR {
+        self.nodes[0].bounds
+    }
+
+    ///Number of boxes
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
+    ///Checks if tree has no boxes
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    ///Box with id
+    pub fn get(&self, id: usize) -> Option<MBR> {
+        let node = *self.locations.get(&id)?;
+        self.nodes[node].items.iter().find(|it| it.0 == id).map(|it| it.1)
+    }
+
+    ///Removes all boxes, keeping the root bounds and configuration
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[0].children = None;
+        self.nodes[0].items.clear();
+        self.locations.clear();
+    }
+
+    ///Inserts box with id, replaces the box of an existing id
+    pub fn insert(&mut self, id: usize, mbr: MBR) {
+        if self.locations.contains_key(&id) {
+            self.remove(id);
+        }
+        let node = self.find_node(&mbr);
+        self.nodes[node].items.push((id, mbr));
+        self.locations.insert(id, node);
+        self.split(node);
+    }
+
+    ///Removes box with id, returns its box if found
+    pub fn remove(&mut self, id: usize) -> Option<MBR> {
+        let node = self.locations.remove(&id)?;
+        let items = &mut self.nodes[node].items;
+        let i = items.iter().position(|it| it.0 == id)?;
+        Some(items.swap_remove(i).1)
+    }
+
+    ///Updates box with id in place if it still fits its node, else moves it.
+    ///Returns false if id is not in the tree.
+    pub fn update(&mut self, id: usize, mbr: MBR) -> bool {
+        let node = match self.locations.get(&id) {
+            Some(&node) => node,
+            None => return false,
+        };
+        if node == 0 || self.nodes[node].loose.contains(&mbr) {
+            if let Some(it) = self.nodes[node].items.iter_mut().find(|it| it.0 == id) {
+                it.1 = mbr;
+            }
+        } else {
+            self.insert(id, mbr);
+        }
+        true
+    }
+
+    ///Ids of boxes that intersect window
+    pub fn query(&self, window: &MBR) -> Vec<usize> {
+        let mut results = vec![];
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if i != 0 && !node.loose.intersects(window) {
+                continue;
+            }
+            results.extend(node.items.iter().filter(|it| it.1.intersects(window)).map(|it| it.0));
+            if let Some(children) = node.children {
+                stack.extend_from_slice(&children);
+            }
+        }
+        results
+    }
+
+    ///Ids of boxes that contain point
+    pub fn query_point(&self, pt: [f64; 2]) -> Vec<usize> {
+        self.query(&MBR::new_from_pt(pt))
+    }
+
+    ///k nearest boxes to point as (id, distance) sorted by distance
+    pub fn nearest(&self, pt: [f64; 2], k: usize) -> Vec<(usize, f64)> {
+        let mut results = vec![];
+        if k == 0 {
+            return results;
+        }
+        let query = MBR::new_from_pt(pt);
+        //best first over nodes and boxes, boxes before nodes at equal distance
+        let mut heap = BinaryHeap::new();
+        heap.push(MinDist::new(0.0, Entry::Node(0)));
+        while let Some(MinDist { dist, item }) = heap.pop() {
+            match item {
+                Entry::Item(id) => {
+                    results.push((id, dist));
+                    if results.len() == k {
+                        break;
+                    }
+                }
+                Entry::Node(i) => {
+                    let node = &self.nodes[i];
+                    for &(id, mbr) in &node.items {
+                        heap.push(MinDist::new(mbr.distance(&query), Entry::Item(id)));
+                    }
+                    for &child in node.children.iter().flatten() {
+                        let d = self.nodes[child].loose.distance(&query);
+                        heap.push(MinDist::new(d, Entry::Node(child)));
+                    }
+                }
+            }
+        }
+        results
+    }
+
+    ///Deepest node whose loose bounds contain mbr, descending by centre
+    fn find_node(&self, mbr: &MBR) -> usize {
+        let c = mbr.centre();
+        let mut i = 0;
+        while let Some(children) = self.nodes[i].children {
+            let child = children[quadrant(&self.nodes[i].bounds, c)];
+            if !self.nodes[child].loose.contains(mbr) {
+                break;
+            }
+            i = child;
+        }
+        i
+    }
+
+    ///Splits leaf over capacity, pushing down boxes that fit its children
+    fn split(&mut self, i: usize) {
+        let node = &self.nodes[i];
+        if node.children.is_some()
+            || node.items.len() <= self.config.capacity
+            || node.depth >= self.config.max_depth
+        {
+            return;
+        }
+        let (b, depth) = (node.bounds, node.depth + 1);
+        let c = b.centre();
+        let quads = [
+            MBR::new_raw(b.minx, b.miny, c[0], c[1]),
+            MBR::new_raw(c[0], b.miny, b.maxx, c[1]),
+            MBR::new_raw(b.minx, c[1], c[0], b.maxy),
+            MBR::new_raw(c[0], c[1], b.maxx, b.maxy),
+        ];
+        let first = self.nodes.len();
+        for q in quads.iter() {
+            self.nodes.push(Node::new(*q, depth, self.config.looseness));
+        }
+        let children = [first, first + 1, first + 2, first + 3];
+        self.nodes[i].children = Some(children);
+
+        let items = std::mem::take(&mut self.nodes[i].items);
+        for (id, mbr) in items {
+            let child = children[quadrant(&b, mbr.centre())];
+            let target = if self.nodes[child].loose.contains(&mbr) { child } else { i };
+            self.nodes[target].items.push((id, mbr));
+            self.locations.insert(id, target);
+        }
+        for &child in children.iter() {
+            self.split(child);
+        }
+    }
+}
+
+///Node or box queued in nearest search
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Entry {
+    Node(usize),
+    Item(usize),
+}
+
+///Quadrant of bounds containing point: 0 ll, 1 lr, 2 ul, 3 ur
+fn quadrant(bounds: &MBR, pt: [f64; 2]) -> usize {
+    let c = bounds.centre();
+    let right = (pt[0] >= c[0]) as usize;
+    let top = (pt[1] >= c[1]) as usize;
+    right + 2 * top
+}
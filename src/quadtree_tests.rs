@@ -0,0 +1,80 @@
+use super::*;
+use crate::test_util::sorted;
+
+fn brute(boxes: &[Option<MBR>], window: &MBR) -> Vec<usize> {
+    (0..boxes.len())
+        .filter(|&i| matches!(boxes[i], Some(b) if b.intersects(window)))
+        .collect()
+}
+
+#[test]
+fn test_quadtree() {
+    let config = QuadTreeConfig { max_depth: 5, capacity: 4, looseness: 2.0 };
+    let mut tree = QuadTree::new(MBR::new(0., 0., 100., 100.), config);
+    assert!(tree.is_empty());
+    assert_eq!(tree.config(), config);
+    assert_eq!(tree.bounds(), MBR::new(0., 0., 100., 100.));
+
+    let mut boxes: Vec<Option<MBR>> = vec![];
+    for i in 0..400 {
+        let (x, y) = ((i * 37 % 97) as f64, (i * 53 % 89) as f64);
+        let size = 0.5 + (i % 7) as f64;
+        boxes.push(Some(MBR::new(x, y, x + size, y + size)));
+    }
+    //large and outside the root bounds
+    boxes.push(Some(MBR::new(10., 10., 90., 90.)));
+    boxes.push(Some(MBR::new(120., -30., 130., -20.)));
+    for (id, b) in boxes.iter().enumerate() {
+        tree.insert(id, b.unwrap());
+    }
+    assert_eq!(tree.len(), boxes.len());
+    assert_eq!(tree.get(401), boxes[401]);
+
+    let windows = [
+        MBR::new(20., 20., 30., 35.),
+        MBR::new(0., 0., 100., 100.),
+        MBR::new(99., 99., 200., 200.),
+        MBR::new(110., -40., 125., -25.),
+        MBR::new(-10., -10., -5., -5.),
+    ];
+    for w in windows.iter() {
+        assert_eq!(sorted(tree.query(w)), brute(&boxes, w));
+    }
+    assert_eq!(sorted(tree.query_point([50., 50.])), brute(&boxes, &MBR::new_from_pt([50., 50.])));
+
+    //move every box a little (in place) and some far away (moved)
+    for (id, b) in boxes.iter_mut().enumerate().take(400) {
+        let moved = match *b {
+            Some(m) if id % 10 == 0 => m.translate(-37., 41.),
+            Some(m) => m.translate(0.3, -0.2),
+            None => continue,
+        };
+        assert!(tree.update(id, moved));
+        *b = Some(moved);
+    }
+    assert!(!tree.update(9999, MBR::new(0., 0., 1., 1.)));
+    for w in windows.iter() {
+        assert_eq!(sorted(tree.query(w)), brute(&boxes, w));
+    }
+
+    //remove
+    for id in (0..400).step_by(3) {
+        assert_eq!(tree.remove(id), boxes[id]);
+        boxes[id] = None;
+    }
+    assert_eq!(tree.remove(0), None);
+    assert_eq!(tree.get(0), None);
+    for w in windows.iter() {
+        assert_eq!(sorted(tree.query(w)), brute(&boxes, w));
+    }
+
+    //reinserting an id replaces its box
+    tree.insert(1, MBR::new(-50., -50., -49., -49.));
+    boxes[1] = Some(MBR::new(-50., -50., -49., -49.));
+    assert_eq!(tree.query(&MBR::new(-60., -60., -40., -40.)), vec![1]);
+    assert_eq!(tree.len(), boxes.iter().filter(|b| b.is_some()).count());
+
+    tree.clear();
+    assert!(tree.is_empty());
+    assert!(tree.query(&windows[1]).is_empty());
+}
@@ -0,0 +1,195 @@
+use crate::{Affine, MBR};
+
+///Values within this distance of an integer snap to it before rounding,
+///so edges that land on pixel boundaries up to float error stay put
+const SNAP_EPS: f64 = 1e-9;
+
+///How fractional pixel (or cell) edges are rounded to whole ones
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    ///Both edges down
+    Floor,
+    ///Both edges up
+    Ceil,
+    ///Both edges to the nearest
+    Round,
+    ///Start edge down and end edge up, covering every touched pixel
+    Outward,
+    ///Start edge up and end edge down, only whole pixels
+    Inward,
+}
+
+impl Rounding {
+    ///Rounds the start (lower) edge
+    pub fn start(self, v: f64) -> f64 {
+        let v = snap(v);
+        match self {
+            Rounding::Floor | Rounding::Outward => v.floor(),
+            Rounding::Ceil | Rounding::Inward => v.ceil(),
+            Rounding::Round => v.round(),
+        }
+    }
+
+    ///Rounds the end (upper) edge
+    pub fn end(self, v: f64) -> f64 {
+        let v = snap(v);
+        match self {
+            Rounding::Floor | Rounding::Inward => v.floor(),
+            Rounding::Ceil | Rounding::Outward => v.ceil(),
+            Rounding::Round => v.round(),
+        }
+    }
+}
+
+fn snap(v: f64) -> f64 {
+    let r = v.round();
+    if (v - r).abs() <= SNAP_EPS { r } else { v }
+}
+
+impl MBR {
+    ///Snaps edges to the cell boundaries of a grid anchored at origin with cell size [dx, dy].
+    ///`Rounding::Outward` expands and `Rounding::Inward` shrinks to whole cells; None if an
+    ///inward snap leaves no whole cell along an axis.
+    ///Panics if the cell size is not positive and finite.
+    pub fn snap_to_grid(
+        &self,
+        origin: [f64; 2],
+        cell: [f64; 2],
+        rounding: Rounding,
+    ) -> Option<MBR> {
+        assert!(
+            cell.iter().all(|d| d.is_finite() && *d > 0.0),
+            "grid cell size must be positive and finite"
+        );
+        let snap = |v0: f64, v1: f64, o: f64, d: f64| {
+            let a = o + rounding.start((v0 - o) / d) * d;
+            let b = o + rounding.end((v1 - o) / d) * d;
+            if rounding == Rounding::Inward && b <= a { None } else { Some((a, b)) }
+        };
+        let (minx, maxx) = snap(self.minx, self.maxx, origin[0], cell[0])?;
+        let (miny, maxy) = snap(self.miny, self.maxy, origin[1], cell[1])?;
+        Some(MBR::new_raw(minx, miny, maxx, maxy))
+    }
+
+    ///Snaps edges to multiples of resolution [dx, dy], a grid anchored at (0, 0)
+    pub fn align_to_resolution(&self, res: [f64; 2], rounding: Rounding) -> Option<MBR> {
+        self.snap_to_grid([0.0, 0.0], res, rounding)
+    }
+
+    ///Rounds coordinates to decimals places, unchanged if 10^decimals is out of f64 range
+    pub fn round_decimals(&self, decimals: i32) -> MBR {
+        let m = 10f64.powi(decimals);
+        if m == 0.0 || !m.is_finite() {
+            return *self;
+        }
+        let r = |v: f64| (v * m).round() / m;
+        MBR::new_raw(r(self.minx), r(self.miny), r(self.maxx), r(self.maxy))
+    }
+}
+
+///GDAL geotransform `[origin x, pixel width, row rotation, origin y, column rotation, pixel height]`
+///mapping pixel (col, row) to world `(gt[0] + col * gt[1] + row * gt[2], gt[3] + col * gt[4] + row * gt[5])`.
+///Pixel height is negative for north up rasters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GeoTransform(pub [f64; 6]);
+
+impl GeoTransform {
+    ///North up geotransform with top left corner origin and positive pixel size res
+    pub fn new(origin: [f64; 2], res: [f64; 2]) -> GeoTransform {
+        GeoTransform([origin[0], res[0], 0.0, origin[1], 0.0, -res[1]])
+    }
+
+    ///North up geotransform of a width x height raster covering extent
+    pub fn from_extent(extent: &MBR, width: usize, height: usize) -> GeoTransform {
+        let res = [extent.width() / width as f64, extent.height() / height as f64];
+        GeoTransform::new([extent.minx, extent.maxy], res)
+    }
+
+    ///Pixel to world transform
+    pub fn affine(&self) -> Affine {
+        let gt = &self.0;
+        Affine { a: gt[1], b: gt[2], c: gt[0], d: gt[4], e: gt[5], f: gt[3] }
+    }
+
+    ///Pixel (col, row) to world point
+    pub fn to_world(&self, px: [f64; 2]) -> [f64; 2] {
+        self.affine().apply(px)
+    }
+
+    ///World point to fractional pixel (col, row), None if the geotransform is singular
+    pub fn to_pixel(&self, pt: [f64; 2]) -> Option<[f64; 2]> {
+        Some(self.affine().inverse()?.apply(pt))
+    }
+
+    ///World extent of a width x height raster
+    pub fn extent(&self, width: usize, height: usize) -> MBR {
+        self.affine().apply_mbr(&MBR::new_raw(0.0, 0.0, width as f64, height as f64))
+    }
+}
+
+///Pixel window of a raster, GDAL `(xoff, yoff, xsize, ysize)`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    pub col_off: i64,
+    pub row_off: i64,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl Window {
+    ///New window
+    pub fn new(col_off: i64, row_off: i64, width: i64, height: i64) -> Window {
+        Window { col_off, row_off, width, height }
+    }
+
+    ///Window covering mbr with edges rounded by rounding, None if the geotransform is singular.
+    ///An inward window of an mbr smaller than a pixel has zero width or height.
+    pub fn from_mbr(mbr: &MBR, gt: &GeoTransform, rounding: Rounding) -> Option<Window> {
+        let px = gt.affine().inverse()?.apply_mbr(mbr);
+        let (c0, r0) = (rounding.start(px.minx), rounding.start(px.miny));
+        let (c1, r1) = (rounding.end(px.maxx), rounding.end(px.maxy));
+        Some(Window {
+            col_off: c0 as i64,
+            row_off: r0 as i64,
+            width: (c1 - c0).max(0.0) as i64,
+            height: (r1 - r0).max(0.0) as i64,
+        })
+    }
+
+    ///Checks if window has no pixels
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    ///Window clamped to a width x height raster, None if they do not overlap
+    pub fn clamp(&self, width: usize, height: usize) -> Option<Window> {
+        let c0 = self.col_off.max(0);
+        let r0 = self.row_off.max(0);
+        let c1 = self.col_off.saturating_add(self.width).min(width as i64);
+        let r1 = self.row_off.saturating_add(self.height).min(height as i64);
+        if c1 <= c0 || r1 <= r0 {
+            return None;
+        }
+        Some(Window { col_off: c0, row_off: r0, width: c1 - c0, height: r1 - r0 })
+    }
+
+    ///World box of window
+    pub fn to_mbr(&self, gt: &GeoTransform) -> MBR {
+        let px = MBR::new_raw(
+            self.col_off as f64,
+            self.row_off as f64,
+            self.col_off.saturating_add(self.width) as f64,
+            self.row_off.saturating_add(self.height) as f64,
+        );
+        gt.affine().apply_mbr(&px)
+    }
+
+    ///Geotransform of the window as a raster of its own
+    pub fn geotransform(&self, gt: &GeoTransform) -> GeoTransform {
+        let origin = gt.to_world([self.col_off as f64, self.row_off as f64]);
+        let mut out = *gt;
+        out.0[0] = origin[0];
+        out.0[3] = origin[1];
+        out
+    }
+}
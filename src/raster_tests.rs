@@ -0,0 +1,90 @@
+use super::*;
+
+#[test]
+fn test_geotransform() {
+    let gt = GeoTransform::new([100., 500.], [10., 10.]);
+    assert_eq!(gt.0, [100., 10., 0., 500., 0., -10.]);
+    assert_eq!(gt.to_world([2., 3.]), [120., 470.]);
+    assert_eq!(gt.to_pixel([120., 470.]), Some([2., 3.]));
+    assert_eq!(gt.extent(40, 30), MBR::new(100., 200., 500., 500.));
+    assert_eq!(GeoTransform::from_extent(&gt.extent(40, 30), 40, 30), gt);
+    assert_eq!(GeoTransform([0.; 6]).to_pixel([1., 1.]), None);
+}
+
+#[test]
+fn test_window() {
+    let gt = GeoTransform::new([0., 100.], [1., 1.]);
+    let mbr = MBR::new(2.5, 80.2, 7.5, 90.);
+    let w = |r| Window::from_mbr(&mbr, &gt, r).unwrap();
+    assert_eq!(w(Rounding::Outward), Window::new(2, 10, 6, 10));
+    assert_eq!(w(Rounding::Inward), Window::new(3, 10, 4, 9));
+    assert_eq!(w(Rounding::Floor), Window::new(2, 10, 5, 9));
+    assert_eq!(w(Rounding::Ceil), Window::new(3, 10, 5, 10));
+    assert_eq!(w(Rounding::Round), Window::new(3, 10, 5, 10));
+
+    //float error on pixel edges is ignored
+    let edge = MBR::new(0.1 + 0.2, 99.7, 1.0 - 1e-12, 100.);
+    let gt10 = GeoTransform::new([0., 100.], [0.1, 0.1]);
+    assert_eq!(Window::from_mbr(&edge, &gt10, Rounding::Outward), Some(Window::new(3, 0, 7, 3)));
+
+    let tiny = MBR::new(2.2, 80.2, 2.4, 80.4);
+    assert!(Window::from_mbr(&tiny, &gt, Rounding::Inward).unwrap().is_empty());
+    assert_eq!(Window::from_mbr(&tiny, &gt, Rounding::Outward), Some(Window::new(2, 19, 1, 1)));
+
+    let win = Window::new(2, 10, 6, 10);
+    assert_eq!(win.to_mbr(&gt), MBR::new(2., 80., 8., 90.));
+    assert_eq!(Window::from_mbr(&win.to_mbr(&gt), &gt, Rounding::Round), Some(win));
+    assert_eq!(win.geotransform(&gt), GeoTransform::new([2., 90.], [1., 1.]));
+}
+
+#[test]
+fn test_window_clamp() {
+    let win = Window::new(-5, 8, 10, 10);
+    assert_eq!(win.clamp(20, 15), Some(Window::new(0, 8, 5, 7)));
+    assert_eq!(win.clamp(100, 100), Some(Window::new(0, 8, 5, 10)));
+    assert_eq!(win.clamp(20, 8), None);
+    assert_eq!(Window::new(-10, 0, 10, 5).clamp(20, 20), None);
+
+    let gt = GeoTransform::from_extent(&MBR::new(0., 0., 100., 100.), 100, 100);
+    let huge = Window::from_mbr(&MBR::new(5., 0., 1e300, 50.), &gt, Rounding::Outward).unwrap();
+    assert_eq!(huge.clamp(100, 100), Some(Window::new(5, 50, 95, 50)));
+}
+
+#[test]
+fn test_snap_to_grid() {
+    let mbr = MBR::new(12.3, -7.5, 47.9, 18.);
+    let snap = |r| mbr.snap_to_grid([2., 0.], [10., 5.], r);
+    assert_eq!(snap(Rounding::Outward), Some(MBR::new(12., -10., 52., 20.)));
+    assert_eq!(snap(Rounding::Inward), Some(MBR::new(22., -5., 42., 15.)));
+    assert_eq!(snap(Rounding::Round), Some(MBR::new(12., -10., 52., 20.)));
+
+    let small = MBR::new(3., 3., 4., 4.);
+    let snap = |r| small.snap_to_grid([0., 0.], [10., 10.], r);
+    assert_eq!(snap(Rounding::Inward), None);
+    assert_eq!(snap(Rounding::Outward), Some(MBR::new(0., 0., 10., 10.)));
+    //a whole cell along x but not along y
+    let wide = MBR::new(-1., 3., 11., 4.);
+    assert_eq!(wide.snap_to_grid([0., 0.], [10., 10.], Rounding::Inward), None);
+    let cell = MBR::new(-1., -1., 11., 11.).snap_to_grid([0., 0.], [10., 10.], Rounding::Inward);
+    assert_eq!(cell, Some(MBR::new(0., 0., 10., 10.)));
+
+    //edges already on the grid up to float error stay put
+    let on = MBR::new(0.1 + 0.2, 0.6, 0.9, 1.2);
+    for &r in [Rounding::Outward, Rounding::Inward].iter() {
+        let aligned = on.align_to_resolution([0.3, 0.3], r).unwrap().round_decimals(9);
+        assert_eq!(aligned, MBR::new(0.3, 0.6, 0.9, 1.2));
+    }
+
+    let res = MBR::new(1.26, 2.74, 3.01, 4.49).align_to_resolution([0.5, 0.25], Rounding::Outward);
+    assert_eq!(res, Some(MBR::new(1., 2.5, 3.5, 4.5)));
+    assert_eq!(MBR::new(1.23456, -2.98765, 3.5, 4.).round_decimals(2), MBR::new(1.23, -2.99, 3.5, 4.));
+    assert_eq!(MBR::new(1234., 0., 1266., 1.).round_decimals(-2), MBR::new(1200., 0., 1300., 0.));
+    assert_eq!(MBR::new(1.5, 2., 3., 4.).round_decimals(400), MBR::new(1.5, 2., 3., 4.));
+    assert_eq!(MBR::new(1.5, 2., 3., 4.).round_decimals(-400), MBR::new(1.5, 2., 3., 4.));
+}
+
+#[test]
+#[should_panic(expected = "grid cell size must be positive and finite")]
+fn test_snap_to_grid_zero_cell() {
+    MBR::new(0., 0., 1., 1.).snap_to_grid([0., 0.], [0., 1.], Rounding::Outward);
+}
@@ -0,0 +1,150 @@
+use crate::{IndexedMBR, MBR};
+use rstar::{RTree, RTreeNode};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+///Ray with origin and direction, points on ray are origin + t * dir for t >= 0
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: [f64; 2],
+    pub dir: [f64; 2],
+}
+
+///Ray hit with entry (tmin) and exit (tmax) parameters along the ray,
+///and the outward normal of the entry face.
+///tmin is negative if the ray origin is inside the box.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit {
+    pub tmin: f64,
+    pub tmax: f64,
+    pub normal: [f64; 2],
+}
+
+impl Ray {
+    ///New ray given origin and direction
+    pub fn new(origin: [f64; 2], dir: [f64; 2]) -> Ray {
+        Ray { origin, dir }
+    }
+
+    ///Point on ray at parameter t
+    #[inline]
+    pub fn at(&self, t: f64) -> [f64; 2] {
+        [self.origin[0] + t * self.dir[0], self.origin[1] + t * self.dir[1]]
+    }
+}
+
+impl RayHit {
+    ///Parameter of the first point of the hit in front of the ray origin
+    #[inline]
+    pub fn t(&self) -> f64 {
+        self.tmin.max(0.0)
+    }
+}
+
+impl MBR {
+    ///Ray intersection by the slab method, None if the ray misses the box
+    pub fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        let mut tmin = f64::NEG_INFINITY;
+        let mut tmax = f64::INFINITY;
+        let mut normal = [0.0, 0.0];
+
+        let slabs = [(self.minx, self.maxx), (self.miny, self.maxy)];
+        for (axis, &(lo, hi)) in slabs.iter().enumerate() {
+            let (o, d) = (ray.origin[axis], ray.dir[axis]);
+            if d == 0.0 {
+                // parallel to slab, origin must be within it
+                if o < lo || o > hi {
+                    return None;
+                }
+                continue;
+            }
+            let inv = 1.0 / d;
+            let (mut t0, mut t1) = ((lo - o) * inv, (hi - o) * inv);
+            let mut n = -1.0;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+                n = 1.0;
+            }
+            if t0 > tmin {
+                tmin = t0;
+                normal = [0.0, 0.0];
+                normal[axis] = n;
+            }
+            tmax = tmax.min(t1);
+            if tmin > tmax {
+                return None;
+            }
+        }
+
+        if tmax < 0.0 {
+            return None;
+        }
+        Some(RayHit { tmin, tmax, normal })
+    }
+}
+
+///Candidate node in best first traversal ordered by nearest t
+struct Candidate<'a> {
+    t: f64,
+    node: &'a RTreeNode<IndexedMBR>,
+}
+
+impl<'a> PartialEq for Candidate<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a> Eq for Candidate<'a> {}
+
+impl<'a> PartialOrd for Candidate<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Candidate<'a> {
+    //reversed for a min heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.t.partial_cmp(&self.t).unwrap_or(Ordering::Equal)
+    }
+}
+
+///Pushes node onto heap if hit by ray
+fn push_candidate<'a>(
+    heap: &mut BinaryHeap<Candidate<'a>>,
+    node: &'a RTreeNode<IndexedMBR>,
+    ray: &Ray,
+) {
+    let mbr = match node {
+        RTreeNode::Leaf(item) => item.mbr,
+        RTreeNode::Parent(parent) => parent.envelope().into(),
+    };
+    if let Some(hit) = mbr.ray_intersection(ray) {
+        heap.push(Candidate { t: hit.t(), node });
+    }
+}
+
+///Nearest box in tree hit by ray as (index, hit).
+///Nodes are visited best first by entry parameter, so the search stops
+///at the first leaf reached.
+pub fn ray_cast(tree: &RTree<IndexedMBR>, ray: &Ray) -> Option<(usize, RayHit)> {
+    let mut heap = BinaryHeap::new();
+    for node in tree.root().children() {
+        push_candidate(&mut heap, node, ray);
+    }
+
+    while let Some(Candidate { node, .. }) = heap.pop() {
+        match node {
+            RTreeNode::Leaf(item) => {
+                return item.mbr.ray_intersection(ray).map(|hit| (item.index, hit));
+            }
+            RTreeNode::Parent(parent) => {
+                for child in parent.children() {
+                    push_candidate(&mut heap, child, ray);
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn test_ray_intersection() {
+    let m = MBR::new(2., 1., 4., 3.);
+
+    let hit = m.ray_intersection(&Ray::new([0., 2.], [1., 0.])).unwrap();
+    assert_eq!((hit.tmin, hit.tmax, hit.normal), (2., 4., [-1., 0.]));
+    assert_eq!(Ray::new([0., 2.], [1., 0.]).at(hit.tmin), [2., 2.]);
+
+    let hit = m.ray_intersection(&Ray::new([3., 5.], [0., -2.])).unwrap();
+    assert_eq!((hit.tmin, hit.tmax, hit.normal), (1., 2., [0., 1.]));
+
+    let hit = m.ray_intersection(&Ray::new([0., -1.], [1., 1.])).unwrap();
+    assert_eq!((hit.tmin, hit.tmax, hit.normal), (2., 4., [-1., 0.]));
+
+    //origin inside
+    let hit = m.ray_intersection(&Ray::new([3., 2.], [1., 0.])).unwrap();
+    assert_eq!((hit.tmin, hit.tmax, hit.t()), (-1., 1., 0.));
+
+    //misses: behind, parallel outside, passing by
+    assert!(m.ray_intersection(&Ray::new([5., 2.], [1., 0.])).is_none());
+    assert!(m.ray_intersection(&Ray::new([0., 4.], [1., 0.])).is_none());
+    assert!(m.ray_intersection(&Ray::new([0., 0.], [1., 2.])).is_none());
+
+    //grazing an edge
+    let hit = m.ray_intersection(&Ray::new([0., 3.], [1., 0.])).unwrap();
+    assert_eq!((hit.tmin, hit.tmax), (2., 4.));
+}
+
+#[test]
+fn test_ray_cast() {
+    let data: Boxes = vec![
+        [10, 0, 12, 2],
+        [4, -1, 5, 1],
+        [7, -3, 8, 3],
+        [2, 5, 3, 6],
+        [-5, -1, -4, 1],
+    ]
+    .into();
+
+    let ray = Ray::new([0., 0.], [1., 0.]);
+    let (i, hit) = data.ray_cast(&ray).unwrap();
+    assert_eq!((i, hit.tmin, hit.normal), (1, 4., [-1., 0.]));
+
+    let ray = Ray::new([0., 0.], [-1., 0.]);
+    let (i, hit) = data.ray_cast(&ray).unwrap();
+    assert_eq!((i, hit.tmin, hit.normal), (4, 4., [1., 0.]));
+
+    let ray = Ray::new([0., 0.], [0., 1.]);
+    assert!(data.ray_cast(&ray).is_none());
+
+    //tree reuse against brute force
+    let mut boxes = vec![];
+    for i in 0..40 {
+        for j in 0..40 {
+            let (x, y) = (i as f64 * 3., j as f64 * 3.);
+            boxes.push(MBR::new(x, y, x + 1. + (i % 3) as f64, y + 1. + (j % 2) as f64));
+        }
+    }
+    let data = Boxes { boxes };
+    let tree = data.rtree();
+    for k in 0..30 {
+        let a = k as f64 * 0.21;
+        let ray = Ray::new([-7., 50.5], [a.cos(), a.sin() - 0.5]);
+        let expects = data
+            .boxes
+            .iter()
+            .filter_map(|b| b.ray_intersection(&ray))
+            .map(|h| h.t())
+            .fold(None, |acc: Option<f64>, t| Some(acc.map_or(t, |v| v.min(t))));
+        let found = ray_cast(&tree, &ray);
+        assert_eq!(found.map(|(_, h)| h.t()), expects);
+        if let Some((i, h)) = found {
+            assert_eq!(data[i].ray_intersection(&ray), Some(h));
+        }
+    }
+}
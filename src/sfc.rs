@@ -0,0 +1,272 @@
+use crate::{Boxes, MBR};
+
+///Hilbert curve index of cell (x, y) on a 2^order x 2^order grid.
+///Panics unless 1 <= order <= 32.
+pub fn hilbert_index(order: u32, x: u32, y: u32) -> u64 {
+    check_order(order);
+    let n: u64 = 1 << order;
+    let (mut x, mut y) = (u64::from(x), u64::from(y));
+    let mut d = 0;
+    let mut s = n >> 1;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        //rotate quadrant
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s >>= 1;
+    }
+    d
+}
+
+///Morton (Z-order) index of cell (x, y), x bits at even and y bits at odd positions
+pub fn morton_index(x: u32, y: u32) -> u64 {
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+///Cell (x, y) of morton index
+pub fn morton_decode(z: u64) -> (u32, u32) {
+    (compact_bits(z), compact_bits(z >> 1))
+}
+
+///Spreads 32 bits of v to the even bits of a u64
+fn spread_bits(v: u32) -> u64 {
+    let mut v = u64::from(v);
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    v = (v | (v << 1)) & 0x5555_5555_5555_5555;
+    v
+}
+
+///Compacts the even bits of a u64 to 32 bits
+fn compact_bits(v: u64) -> u32 {
+    let mut v = v & 0x5555_5555_5555_5555;
+    v = (v | (v >> 1)) & 0x3333_3333_3333_3333;
+    v = (v | (v >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v >> 4)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v >> 8)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v >> 16)) & 0x0000_0000_FFFF_FFFF;
+    v as u32
+}
+
+///Space filling curve
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Curve {
+    Morton,
+    Hilbert,
+}
+
+///Key ranges `(lo, hi)` (inclusive) on curve that cover query window within extent,
+///on a 2^order x 2^order grid. Panics unless 1 <= order <= 32.
+///At most max_ranges ranges are returned, fewer ranges cover more cells outside the window
+///(false positives) while more ranges mean more seeks in a sorted key-value store.
+///
+///Boxes keyed by `hilbert_key`/`morton_key` are keyed by their centres, so a box can
+///intersect the window while its centre lies outside it. To find every intersecting box,
+///expand the window by the largest half width and half height of the stored boxes
+///(`expand_by_delta`) before computing ranges, then filter the scanned boxes by `intersects`.
+pub fn key_ranges(
+    query: &MBR,
+    extent: &MBR,
+    order: u32,
+    curve: Curve,
+    max_ranges: usize,
+) -> Vec<(u64, u64)> {
+    check_order(order);
+    let max_ranges = max_ranges.max(1);
+    if !query.intersects(extent) {
+        return vec![];
+    }
+    let (x0, y0) = grid_cell(extent, order, query.ll());
+    let (x1, y1) = grid_cell(extent, order, query.ur());
+
+    //quadtree blocks (level, x, y, is_inside) refined level by level,
+    //refinement stops once it would exceed the budget
+    let budget = 4 * max_ranges;
+    let mut blocks = vec![(0u32, 0u32, 0u32, false)];
+    let mut level = 0;
+    while level < order && blocks.iter().any(|b| !b.3) {
+        let partial = blocks.iter().filter(|b| !b.3).count();
+        if blocks.len() + 3 * partial > budget && level > 0 {
+            break;
+        }
+        let mut next = Vec::with_capacity(blocks.len() + 3 * partial);
+        for &(l, bx, by, inside) in &blocks {
+            if inside {
+                next.push((l, bx, by, inside));
+                continue;
+            }
+            let shift = order - l - 1;
+            for &(cx, cy) in [(0, 0), (0, 1), (1, 0), (1, 1)].iter() {
+                let (px, py) = (bx * 2 + cx, by * 2 + cy);
+                let (lx, ly) = (px << shift, py << shift);
+                let (ux, uy) = (lx + ((1 << shift) - 1), ly + ((1 << shift) - 1));
+                if ux < x0 || lx > x1 || uy < y0 || ly > y1 {
+                    continue;
+                }
+                let inside = lx >= x0 && ux <= x1 && ly >= y0 && uy <= y1;
+                next.push((l + 1, px, py, inside));
+            }
+        }
+        blocks = next;
+        level += 1;
+    }
+
+    //blocks at level l are contiguous key ranges on both curves
+    let mut ranges: Vec<(u64, u64)> = blocks
+        .iter()
+        .map(|&(l, bx, by, _)| {
+            let key = match curve {
+                Curve::Morton => morton_index(bx, by),
+                Curve::Hilbert => hilbert_index(l, bx, by),
+            };
+            let shift = 2 * (order - l);
+            let lo = u128::from(key) << shift;
+            let hi = ((u128::from(key) + 1) << shift) - 1;
+            (lo as u64, hi as u64)
+        })
+        .collect();
+    ranges.sort_unstable();
+    merge_ranges(ranges, max_ranges)
+}
+
+///Merges adjacent ranges, then the ranges with the smallest gaps
+///until there are at most max_ranges
+fn merge_ranges(ranges: Vec<(u64, u64)>, max_ranges: usize) -> Vec<(u64, u64)> {
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (lo, hi) in ranges {
+        match merged.last_mut() {
+            Some(last) if lo <= last.1.saturating_add(1) => last.1 = last.1.max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+    if merged.len() <= max_ranges {
+        return merged;
+    }
+
+    let mut gaps: Vec<(u64, usize)> = merged
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| (w[1].0 - w[0].1, i))
+        .collect();
+    gaps.sort_unstable();
+    let mut join = vec![false; merged.len()];
+    for &(_, i) in gaps.iter().take(merged.len() - max_ranges) {
+        join[i] = true;
+    }
+
+    let mut out: Vec<(u64, u64)> = Vec::with_capacity(max_ranges);
+    let mut joining = false;
+    for (i, &(lo, hi)) in merged.iter().enumerate() {
+        match out.last_mut() {
+            Some(last) if joining => last.1 = hi,
+            _ => out.push((lo, hi)),
+        }
+        joining = join[i];
+    }
+    out
+}
+
+///LITMAX and BIGMIN (Tropf & Herzog) of morton index z with zmin < z < zmax
+///outside the query window with corner indices zmin and zmax:
+///the largest index in the window less than z and the smallest index in the window
+///greater than z, for skipping ahead while scanning a sorted key range.
+///As with `key_ranges`, keys of box centres only find boxes whose centres lie in the
+///window; expand the window by the largest half extents of the boxes first.
+pub fn morton_litmax_bigmin(z: u64, zmin: u64, zmax: u64) -> (u64, u64) {
+    let (mut zmin, mut zmax) = (zmin, zmax);
+    let (mut litmax, mut bigmin) = (zmin, zmax);
+    for bit in (0..64).rev() {
+        let mask = 1u64 << bit;
+        let dim = if bit % 2 == 0 { 0x5555_5555_5555_5555 } else { 0xAAAA_AAAA_AAAA_AAAA };
+        //lower bits of the same dimension
+        let lower = dim & (mask - 1);
+        let load_1000 = |v: u64| (v | mask) & !lower;
+        let load_0111 = |v: u64| (v & !mask) | lower;
+
+        match (z & mask != 0, zmin & mask != 0, zmax & mask != 0) {
+            (false, false, true) => {
+                bigmin = load_1000(zmin);
+                zmax = load_0111(zmax);
+            }
+            (false, true, true) => {
+                bigmin = zmin;
+                break;
+            }
+            (true, false, false) => {
+                litmax = zmax;
+                break;
+            }
+            (true, false, true) => {
+                litmax = load_0111(zmax);
+                zmin = load_1000(zmin);
+            }
+            _ => {}
+        }
+    }
+    (litmax, bigmin)
+}
+
+///Keys of orders above 32 do not fit in u64
+fn check_order(order: u32) {
+    assert!((1..=32).contains(&order), "curve order must be in 1..=32");
+}
+
+///Grid cell of point within extent on a 2^order x 2^order grid,
+///points outside the extent are clamped to the border cells
+pub(crate) fn grid_cell(extent: &MBR, order: u32, pt: [f64; 2]) -> (u32, u32) {
+    let n = (1u64 << order) as f64;
+    let cell = |v: f64, min: f64, size: f64| {
+        if size > 0.0 {
+            ((v - min) / size * n).floor().max(0.0).min(n - 1.0) as u32
+        } else {
+            0
+        }
+    };
+    (
+        cell(pt[0], extent.minx, extent.width()),
+        cell(pt[1], extent.miny, extent.height()),
+    )
+}
+
+impl MBR {
+    ///Hilbert key of centre within extent on a 2^order x 2^order grid.
+    ///Panics unless 1 <= order <= 32.
+    pub fn hilbert_key(&self, extent: &MBR, order: u32) -> u64 {
+        check_order(order);
+        let (x, y) = grid_cell(extent, order, self.centre());
+        hilbert_index(order, x, y)
+    }
+
+    ///Morton (Z-order) key of centre within extent on a 2^order x 2^order grid.
+    ///Panics unless 1 <= order <= 32.
+    pub fn morton_key(&self, extent: &MBR, order: u32) -> u64 {
+        check_order(order);
+        let (x, y) = grid_cell(extent, order, self.centre());
+        morton_index(x, y)
+    }
+}
+
+impl Boxes {
+    ///Sorts boxes by hilbert key of centres within the extent of boxes
+    pub fn sort_by_hilbert(&mut self, order: u32) {
+        if let Some(extent) = self.extent() {
+            self.boxes.sort_by_cached_key(|b| b.hilbert_key(&extent, order));
+        }
+    }
+
+    ///Sorts boxes by morton key of centres within the extent of boxes
+    pub fn sort_by_morton(&mut self, order: u32) {
+        if let Some(extent) = self.extent() {
+            self.boxes.sort_by_cached_key(|b| b.morton_key(&extent, order));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::*;
+
+#[test]
+fn test_hilbert_index() {
+    //order 1: ll, ul, ur, lr
+    assert_eq!(
+        (hilbert_index(1, 0, 0), hilbert_index(1, 0, 1), hilbert_index(1, 1, 1), hilbert_index(1, 1, 0)),
+        (0, 1, 2, 3)
+    );
+
+    //bijective and continuous: consecutive indices are neighbouring cells
+    let order = 4;
+    let n = 1u32 << order;
+    let mut cells = vec![(0, 0); (n * n) as usize];
+    for x in 0..n {
+        for y in 0..n {
+            cells[hilbert_index(order, x, y) as usize] = (x, y);
+        }
+    }
+    for w in cells.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let d = (a.0 as i64 - b.0 as i64).abs() + (a.1 as i64 - b.1 as i64).abs();
+        assert_eq!(d, 1);
+    }
+    assert_eq!(hilbert_index(32, u32::MAX, 0), u64::MAX);
+}
+
+#[test]
+fn test_morton_index() {
+    assert_eq!(morton_index(0, 0), 0);
+    assert_eq!(morton_index(1, 0), 1);
+    assert_eq!(morton_index(0, 1), 2);
+    assert_eq!(morton_index(3, 5), 0b100111);
+    assert_eq!(morton_index(u32::MAX, u32::MAX), u64::MAX);
+    for &(x, y) in [(0, 0), (3, 5), (12345, 987654), (u32::MAX, 7)].iter() {
+        assert_eq!(morton_decode(morton_index(x, y)), (x, y));
+    }
+}
+
+#[test]
+fn test_keys() {
+    let extent = MBR::new(0., 0., 8., 8.);
+    let m = MBR::new(5., 1., 6., 2.); //centre in cell (2, 0) at order 2
+    assert_eq!(m.morton_key(&extent, 2), morton_index(2, 0));
+    assert_eq!(m.hilbert_key(&extent, 2), hilbert_index(2, 2, 0));
+    assert_eq!(m.hilbert_key(&extent, 1), 3);
+
+    //outside the extent clamps to border cells
+    let o = MBR::new(100., 100., 101., 101.);
+    assert_eq!(o.morton_key(&extent, 2), morton_index(3, 3));
+    let o = MBR::new(-100., 3., -99., 4.);
+    assert_eq!(o.morton_key(&extent, 2), morton_index(0, 1));
+
+    //degenerate extent
+    let line = MBR::new(0., 0., 8., 0.);
+    assert_eq!(m.morton_key(&line, 2), morton_index(2, 0));
+
+    let mut data: Boxes = vec![[6, 6, 7, 7], [6, 0, 7, 1], [0, 0, 1, 1], [0, 6, 1, 7]].into();
+    assert_eq!(data.extent(), Some(MBR::new(0., 0., 7., 7.)));
+    data.sort_by_hilbert(8);
+    assert_eq!(data[0], MBR::new(0., 0., 1., 1.));
+    assert_eq!(data[1], MBR::new(0., 6., 1., 7.));
+    assert_eq!(data[2], MBR::new(6., 6., 7., 7.));
+    assert_eq!(data[3], MBR::new(6., 0., 7., 1.));
+
+    data.sort_by_morton(8);
+    assert_eq!(data[0], MBR::new(0., 0., 1., 1.));
+    assert_eq!(data[1], MBR::new(6., 0., 7., 1.));
+    assert_eq!(data[2], MBR::new(0., 6., 1., 7.));
+    assert_eq!(data[3], MBR::new(6., 6., 7., 7.));
+
+    let mut empty = Boxes { boxes: vec![] };
+    assert_eq!(empty.extent(), None);
+    empty.sort_by_hilbert(8);
+}
+
+#[test]
+#[should_panic(expected = "curve order must be in 1..=32")]
+fn test_hilbert_index_order() {
+    hilbert_index(33, 0, 0);
+}
+
+#[test]
+#[should_panic(expected = "curve order must be in 1..=32")]
+fn test_morton_key_order() {
+    MBR::new(0., 0., 1., 1.).morton_key(&MBR::new(0., 0., 8., 8.), 40);
+}
+
+#[test]
+#[should_panic(expected = "curve order must be in 1..=32")]
+fn test_hilbert_key_order() {
+    MBR::new(0., 0., 1., 1.).hilbert_key(&MBR::new(0., 0., 8., 8.), 0);
+}
+
+#[test]
+fn test_key_ranges() {
+    let order = 4;
+    let n = 1u32 << order;
+    let extent = MBR::new(0., 0., 16., 16.);
+    let queries = [
+        MBR::new(3.5, 2.5, 9.5, 7.5),
+        MBR::new(0., 0., 7.9, 7.9),
+        MBR::new(12.2, 0.5, 12.3, 15.5),
+        MBR::new(-5., 10.5, 3.5, 30.),
+    ];
+    for query in queries.iter() {
+        let (x0, y0) = (query.minx.max(0.) as u32, query.miny.max(0.) as u32);
+        let (x1, y1) = (query.maxx.min(15.) as u32, query.maxy.min(15.) as u32);
+        for &curve in [Curve::Morton, Curve::Hilbert].iter() {
+            let key = |x, y| match curve {
+                Curve::Morton => morton_index(x, y),
+                Curve::Hilbert => hilbert_index(order, x, y),
+            };
+            let inside = |x, y| x >= x0 && x <= x1 && y >= y0 && y <= y1;
+            let covered = |ranges: &[(u64, u64)], k| ranges.iter().any(|r| r.0 <= k && k <= r.1);
+
+            //exact cover with enough ranges
+            let ranges = key_ranges(query, &extent, order, curve, 1000);
+            for w in ranges.windows(2) {
+                assert!(w[0].1 + 1 < w[1].0);
+            }
+            for x in 0..n {
+                for y in 0..n {
+                    assert_eq!(covered(&ranges, key(x, y)), inside(x, y));
+                }
+            }
+
+            //bounded number of ranges, still a cover
+            for &max in [1, 2, 3, 5].iter() {
+                let few = key_ranges(query, &extent, order, curve, max);
+                assert!(few.len() <= max);
+                for x in 0..n {
+                    for y in 0..n {
+                        if inside(x, y) {
+                            assert!(covered(&few, key(x, y)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let outside = MBR::new(20., 20., 30., 30.);
+    assert!(key_ranges(&outside, &extent, order, Curve::Morton, 8).is_empty());
+    let all = key_ranges(&extent, &extent, 32, Curve::Hilbert, 8);
+    assert_eq!(all, vec![(0, u64::MAX)]);
+}
+
+#[test]
+#[should_panic(expected = "curve order must be in 1..=32")]
+fn test_key_ranges_order() {
+    let extent = MBR::new(0., 0., 8., 8.);
+    key_ranges(&MBR::new(1., 1., 2., 2.), &extent, 33, Curve::Morton, 4);
+}
+
+#[test]
+fn test_litmax_bigmin() {
+    let (x0, y0, x1, y1) = (3u32, 5u32, 10u32, 9u32);
+    let (zmin, zmax) = (morton_index(x0, y0), morton_index(x1, y1));
+    let inside = |z: u64| {
+        let (x, y) = morton_decode(z);
+        x >= x0 && x <= x1 && y >= y0 && y <= y1
+    };
+    for z in zmin + 1..zmax {
+        if inside(z) {
+            continue;
+        }
+        let litmax = (zmin..z).rev().find(|&v| inside(v)).unwrap();
+        let bigmin = (z + 1..=zmax).find(|&v| inside(v)).unwrap();
+        assert_eq!(morton_litmax_bigmin(z, zmin, zmax), (litmax, bigmin));
+    }
+}
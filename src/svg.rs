@@ -0,0 +1,252 @@
+use crate::{Boxes, MBR};
+use std::fmt::{Display, Error, Formatter, Write};
+use std::path::Path;
+
+///Stroke and fill of a box drawn to SVG
+#[derive(Clone, Debug, PartialEq)]
+pub struct Style {
+    ///Stroke colour, any SVG paint ("red", "#ff0000", "none")
+    pub stroke: String,
+    ///Fill colour, any SVG paint
+    pub fill: String,
+    ///Stroke width in pixels
+    pub stroke_width: f64,
+    ///Fill opacity in [0, 1]
+    pub fill_opacity: f64,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style::new("black", "none")
+    }
+}
+
+impl Style {
+    ///Style with stroke and fill colours
+    pub fn new(stroke: &str, fill: &str) -> Style {
+        Style { stroke: stroke.into(), fill: fill.into(), stroke_width: 1.0, fill_opacity: 0.3 }
+    }
+}
+
+///SVG writer for boxes.
+///
+///Boxes are drawn in world coordinates fitted into the canvas, keeping their
+///aspect ratio, with the y axis pointing up by default.
+#[derive(Clone, Debug)]
+pub struct Svg {
+    width: f64,
+    height: f64,
+    margin: f64,
+    font_size: f64,
+    flip_y: bool,
+    viewport: Option<MBR>,
+    shapes: Vec<(MBR, Style, Option<String>)>,
+}
+
+impl Svg {
+    ///New SVG canvas of width x height pixels
+    pub fn new(width: f64, height: f64) -> Svg {
+        Svg {
+            width,
+            height,
+            margin: 10.0,
+            font_size: 12.0,
+            flip_y: true,
+            viewport: None,
+            shapes: vec![],
+        }
+    }
+
+    ///Margin in pixels around the drawing
+    pub fn margin(&mut self, margin: f64) -> &mut Self {
+        self.margin = margin.max(0.0);
+        self
+    }
+
+    ///Font size of labels in pixels
+    pub fn font_size(&mut self, size: f64) -> &mut Self {
+        self.font_size = size;
+        self
+    }
+
+    ///Flips the y axis so world y points up, true by default
+    pub fn flip_y(&mut self, flip: bool) -> &mut Self {
+        self.flip_y = flip;
+        self
+    }
+
+    ///Fixes the world viewport, by default it fits all boxes
+    pub fn viewport(&mut self, viewport: MBR) -> &mut Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    ///Adds box
+    pub fn add(&mut self, mbr: &MBR, style: &Style) -> &mut Self {
+        self.shapes.push((*mbr, style.clone(), None));
+        self
+    }
+
+    ///Adds box with label at its centre
+    pub fn add_labeled(&mut self, mbr: &MBR, style: &Style, label: &str) -> &mut Self {
+        self.shapes.push((*mbr, style.clone(), Some(label.into())));
+        self
+    }
+
+    ///Adds point as a degenerate box
+    pub fn add_point(&mut self, pt: [f64; 2], style: &Style) -> &mut Self {
+        self.add(&MBR::new_from_pt(pt), style)
+    }
+
+    ///Adds boxes labeled by their index
+    pub fn add_boxes(&mut self, boxes: &Boxes, style: &Style) -> &mut Self {
+        for (i, mbr) in boxes.boxes.iter().enumerate() {
+            self.add_labeled(mbr, style, &i.to_string());
+        }
+        self
+    }
+
+    ///World viewport: the fixed viewport or the extent of all boxes
+    pub fn world(&self) -> Option<MBR> {
+        if self.viewport.is_some() {
+            return self.viewport;
+        }
+        let mut iter = self.shapes.iter().map(|s| s.0);
+        let mut extent = iter.next()?;
+        for mbr in iter {
+            extent.expand_to_include(&mbr);
+        }
+        Some(extent)
+    }
+
+    ///Maps world point to canvas pixels
+    pub fn to_screen(&self, pt: [f64; 2]) -> [f64; 2] {
+        match self.transform() {
+            Some(t) => t.apply(pt),
+            None => pt,
+        }
+    }
+
+    ///World to canvas transform, None if there is nothing to draw
+    fn transform(&self) -> Option<Transform> {
+        let world = self.world()?;
+        let (w, h) = (self.width - 2.0 * self.margin, self.height - 2.0 * self.margin);
+        let scale = match (world.width() > 0.0, world.height() > 0.0) {
+            (true, true) => (w / world.width()).min(h / world.height()),
+            (true, false) => w / world.width(),
+            (false, true) => h / world.height(),
+            (false, false) => 1.0,
+        };
+        //centre the drawing in the canvas
+        let ox = self.margin + (w - world.width() * scale) / 2.0;
+        let oy = self.margin + (h - world.height() * scale) / 2.0;
+        Some(Transform { world, scale, ox, oy, flip_y: self.flip_y })
+    }
+
+    ///Writes SVG document to file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+///Display writes the SVG document
+impl Display for Svg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut doc = String::new();
+        writeln!(
+            doc,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = num(self.width),
+            h = num(self.height)
+        )?;
+        let transform = self.transform();
+        let to_screen = |pt: [f64; 2]| transform.as_ref().map_or(pt, |t| t.apply(pt));
+        for (mbr, style, label) in &self.shapes {
+            let a = to_screen([mbr.minx, mbr.miny]);
+            let b = to_screen([mbr.maxx, mbr.maxy]);
+            let (x, y) = (a[0].min(b[0]), a[1].min(b[1]));
+            let (w, h) = ((a[0] - b[0]).abs(), (a[1] - b[1]).abs());
+            if w == 0.0 && h == 0.0 {
+                writeln!(
+                    doc,
+                    r#"  <circle cx="{}" cy="{}" r="{}" fill="{}" stroke="{}" stroke-width="{}"/>"#,
+                    num(x),
+                    num(y),
+                    num(2.0 * style.stroke_width),
+                    escape(&style.stroke),
+                    escape(&style.stroke),
+                    num(style.stroke_width)
+                )?;
+            } else {
+                writeln!(
+                    doc,
+                    r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="{}"/>"#,
+                    num(x),
+                    num(y),
+                    num(w),
+                    num(h),
+                    escape(&style.fill),
+                    num(style.fill_opacity),
+                    escape(&style.stroke),
+                    num(style.stroke_width)
+                )?;
+            }
+            if let Some(label) = label {
+                writeln!(
+                    doc,
+                    r#"  <text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+                    num(x + w / 2.0),
+                    num(y + h / 2.0),
+                    num(self.font_size),
+                    escape(label)
+                )?;
+            }
+        }
+        doc.push_str("</svg>\n");
+        f.write_str(&doc)
+    }
+}
+
+///World to canvas mapping computed once per document
+struct Transform {
+    world: MBR,
+    scale: f64,
+    ox: f64,
+    oy: f64,
+    flip_y: bool,
+}
+
+impl Transform {
+    fn apply(&self, pt: [f64; 2]) -> [f64; 2] {
+        let x = self.ox + (pt[0] - self.world.minx) * self.scale;
+        let y = if self.flip_y {
+            self.oy + (self.world.maxy - pt[1]) * self.scale
+        } else {
+            self.oy + (pt[1] - self.world.miny) * self.scale
+        };
+        [x, y]
+    }
+}
+
+///Number with at most 3 decimals
+fn num(v: f64) -> String {
+    let s = format!("{:.3}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".into() } else { s.into() }
+}
+
+///Escapes XML special characters
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
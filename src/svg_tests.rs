@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn test_svg_viewport() {
+    let mut svg = Svg::new(220., 120.);
+    svg.add(&MBR::new(0., 0., 10., 5.), &Style::default());
+    assert_eq!(svg.world(), Some(MBR::new(0., 0., 10., 5.)));
+    //scale 20, y up
+    assert_eq!(svg.to_screen([0., 0.]), [10., 110.]);
+    assert_eq!(svg.to_screen([10., 5.]), [210., 10.]);
+    svg.flip_y(false);
+    assert_eq!(svg.to_screen([0., 0.]), [10., 10.]);
+
+    //letterboxed and centred
+    let mut svg = Svg::new(120., 120.);
+    svg.margin(0.).viewport(MBR::new(0., 0., 4., 2.));
+    assert_eq!(svg.to_screen([0., 2.]), [0., 30.]);
+    assert_eq!(svg.to_screen([4., 0.]), [120., 90.]);
+    assert_eq!(Svg::new(10., 10.).world(), None);
+}
+
+#[test]
+fn test_svg_document() {
+    let a = MBR::new(0., 0., 2., 2.);
+    let b = MBR::new(1., 1., 3., 4.);
+    let mut svg = Svg::new(200., 200.);
+    svg.add_labeled(&a, &Style::new("red", "pink"), "a & b")
+        .add(&b, &Style::new("blue", "none"))
+        .add(&(&a & &b).unwrap(), &Style::new("green", "green"))
+        .add_point([3., 0.], &Style::default());
+    let doc = svg.to_string();
+    assert!(doc.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="200""#));
+    assert!(doc.ends_with("</svg>\n"));
+    assert_eq!(doc.matches("<rect").count(), 3);
+    assert_eq!(doc.matches("<circle").count(), 1);
+    assert!(doc.contains(r#"stroke="red""#));
+    assert!(doc.contains(">a &amp; b</text>"));
+
+    let data: Boxes = vec![[0, 0, 1, 1], [2, 2, 3, 3]].into();
+    let mut svg = Svg::new(100., 100.);
+    svg.add_boxes(&data, &Style::default());
+    let doc = svg.to_string();
+    assert_eq!(doc.matches("<text").count(), 2);
+    assert!(doc.contains(">1</text>"));
+}
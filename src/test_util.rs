@@ -0,0 +1,28 @@
+use crate::{Boxes, MBR};
+
+///Seeded uniform random numbers in [0, 1) from a 64 bit LCG
+pub fn lcg(seed: u64) -> impl FnMut() -> f64 {
+    let mut seed = seed;
+    move || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+///n seeded random boxes with lower left corners in [0, 100) and sides in [0, max_size)
+pub fn random_boxes(n: usize, seed: u64, max_size: f64) -> Boxes {
+    let mut rand = lcg(seed);
+    let boxes = (0..n)
+        .map(|_| {
+            let (x, y) = (rand() * 100., rand() * 100.);
+            MBR::new(x, y, x + rand() * max_size, y + rand() * max_size)
+        })
+        .collect();
+    Boxes { boxes }
+}
+
+///Ids in ascending order
+pub fn sorted(mut v: Vec<usize>) -> Vec<usize> {
+    v.sort_unstable();
+    v
+}
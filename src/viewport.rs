@@ -0,0 +1,215 @@
+use crate::MBR;
+
+///Metres per inch, for scale denominators
+const METRES_PER_INCH: f64 = 0.0254;
+
+///2D affine transform `x' = a*x + b*y + c`, `y' = d*x + e*y + f`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Affine::identity()
+    }
+}
+
+impl Affine {
+    ///Identity transform
+    pub fn identity() -> Affine {
+        Affine::scale(1.0, 1.0)
+    }
+
+    ///Translation by tx, ty
+    pub fn translation(tx: f64, ty: f64) -> Affine {
+        Affine { a: 1.0, b: 0.0, c: tx, d: 0.0, e: 1.0, f: ty }
+    }
+
+    ///Scale by sx, sy about the origin
+    pub fn scale(sx: f64, sy: f64) -> Affine {
+        Affine { a: sx, b: 0.0, c: 0.0, d: 0.0, e: sy, f: 0.0 }
+    }
+
+    ///Transforms point
+    pub fn apply(&self, pt: [f64; 2]) -> [f64; 2] {
+        [
+            self.a * pt[0] + self.b * pt[1] + self.c,
+            self.d * pt[0] + self.e * pt[1] + self.f,
+        ]
+    }
+
+    ///Bounds of the transformed corners of mbr
+    pub fn apply_mbr(&self, mbr: &MBR) -> MBR {
+        let corners = [
+            self.apply([mbr.minx, mbr.miny]),
+            self.apply([mbr.minx, mbr.maxy]),
+            self.apply([mbr.maxx, mbr.miny]),
+            self.apply([mbr.maxx, mbr.maxy]),
+        ];
+        let mut out = MBR::new_from_pt(corners[0]);
+        for pt in &corners[1..] {
+            out.expand_to_include_point(*pt);
+        }
+        out
+    }
+
+    ///Transform applying self then other
+    pub fn then(&self, other: &Affine) -> Affine {
+        Affine {
+            a: other.a * self.a + other.b * self.d,
+            b: other.a * self.b + other.b * self.e,
+            c: other.a * self.c + other.b * self.f + other.c,
+            d: other.d * self.a + other.e * self.d,
+            e: other.d * self.b + other.e * self.e,
+            f: other.d * self.c + other.e * self.f + other.f,
+        }
+    }
+
+    ///Determinant of the linear part
+    pub fn determinant(&self) -> f64 {
+        self.a * self.e - self.b * self.d
+    }
+
+    ///Inverse transform, None if singular
+    pub fn inverse(&self) -> Option<Affine> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (a, b, d, e) = (self.e / det, -self.b / det, -self.d / det, self.a / det);
+        Some(Affine {
+            a,
+            b,
+            c: -(a * self.c + b * self.f),
+            d,
+            e,
+            f: -(d * self.c + e * self.f),
+        })
+    }
+}
+
+///How a world box is fitted to the aspect ratio of a viewport
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fit {
+    ///Expand the world so all of it is visible
+    Letterbox,
+    ///Shrink the world so it fills the viewport
+    Crop,
+    ///Keep the world, scaling x and y independently
+    Stretch,
+}
+
+///Mapping between a world box and a width x height pixel viewport.
+///
+///Pixel (0, 0) is the top left corner and the y axis points down; world
+///y points up. Operations return new viewports.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Viewport {
+    ///World box shown in the viewport
+    pub world: MBR,
+    ///Width in pixels
+    pub width: f64,
+    ///Height in pixels
+    pub height: f64,
+}
+
+impl Viewport {
+    ///Viewport showing world in width x height pixels, fitted to their aspect ratio.
+    ///A point world is kept as is. A line world is letterboxed to the aspect ratio by
+    ///`Fit::Letterbox` and kept as is by `Fit::Crop` and `Fit::Stretch`.
+    pub fn new(world: MBR, width: f64, height: f64, fit: Fit) -> Viewport {
+        let target = width / height;
+        let (ww, wh) = (world.width(), world.height());
+        let (mut w, mut h) = (ww, wh);
+        let wider = ww * height > wh * width;
+        match fit {
+            Fit::Letterbox if wider => h = ww / target,
+            Fit::Letterbox => w = wh * target,
+            Fit::Crop if wider => w = wh * target,
+            Fit::Crop => h = ww / target,
+            Fit::Stretch => {}
+        }
+        if !(w > 0.0 && h > 0.0 && w.is_finite() && h.is_finite()) {
+            w = ww;
+            h = wh;
+        }
+        let c = world.centre();
+        let world = MBR::new_raw(c[0] - w / 2.0, c[1] - h / 2.0, c[0] + w / 2.0, c[1] + h / 2.0);
+        Viewport { world, width, height }
+    }
+
+    ///World units per pixel in x and y
+    pub fn resolution(&self) -> [f64; 2] {
+        [self.world.width() / self.width, self.world.height() / self.height]
+    }
+
+    ///Scale denominator (1:n) at dpi, for world units of metres_per_unit metres
+    pub fn scale_denominator(&self, dpi: f64, metres_per_unit: f64) -> f64 {
+        self.resolution()[0] * metres_per_unit * dpi / METRES_PER_INCH
+    }
+
+    ///Zooms in by factor (> 1 zooms in) keeping world point `about` fixed on screen
+    pub fn zoom(&self, factor: f64, about: [f64; 2]) -> Viewport {
+        let w = &self.world;
+        let scale = |v: f64, at: f64| at + (v - at) / factor;
+        let world = MBR::new_raw(
+            scale(w.minx, about[0]),
+            scale(w.miny, about[1]),
+            scale(w.maxx, about[0]),
+            scale(w.maxy, about[1]),
+        );
+        Viewport { world, ..*self }
+    }
+
+    ///Pans the world by dx, dy world units
+    pub fn pan(&self, dx: f64, dy: f64) -> Viewport {
+        let w = &self.world;
+        let world = MBR::new_raw(w.minx + dx, w.miny + dy, w.maxx + dx, w.maxy + dy);
+        Viewport { world, ..*self }
+    }
+
+    ///Pans the view by dx, dy pixels, content follows the pointer
+    pub fn pan_pixels(&self, dx: f64, dy: f64) -> Viewport {
+        let [rx, ry] = self.resolution();
+        self.pan(-dx * rx, dy * ry)
+    }
+
+    ///World to pixel transform
+    pub fn transform(&self) -> Affine {
+        let w = &self.world;
+        let (sx, sy) = (self.width / w.width(), self.height / w.height());
+        Affine { a: sx, b: 0.0, c: -w.minx * sx, d: 0.0, e: -sy, f: w.maxy * sy }
+    }
+
+    ///Pixel to world transform
+    pub fn inverse_transform(&self) -> Affine {
+        let [rx, ry] = self.resolution();
+        Affine { a: rx, b: 0.0, c: self.world.minx, d: 0.0, e: -ry, f: self.world.maxy }
+    }
+
+    ///World point to pixel
+    pub fn to_screen(&self, pt: [f64; 2]) -> [f64; 2] {
+        self.transform().apply(pt)
+    }
+
+    ///Pixel to world point
+    pub fn to_world(&self, px: [f64; 2]) -> [f64; 2] {
+        self.inverse_transform().apply(px)
+    }
+
+    ///World box to pixel box
+    pub fn screen_mbr(&self, mbr: &MBR) -> MBR {
+        self.transform().apply_mbr(mbr)
+    }
+
+    ///Pixel box to world box
+    pub fn world_mbr(&self, px: &MBR) -> MBR {
+        self.inverse_transform().apply_mbr(px)
+    }
+}
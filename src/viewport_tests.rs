@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn test_affine() {
+    let t = Affine::scale(2., 3.).then(&Affine::translation(1., -1.));
+    assert_eq!(t.apply([1., 1.]), [3., 2.]);
+    let inv = t.inverse().unwrap();
+    assert_eq!(inv.apply([3., 2.]), [1., 1.]);
+    assert_eq!(t.then(&inv), Affine::identity());
+    assert_eq!(Affine::scale(0., 1.).inverse(), None);
+    assert_eq!(t.apply_mbr(&MBR::new(0., 0., 1., 1.)), MBR::new(1., -1., 3., 2.));
+    assert_eq!(Affine::default().apply([4., 5.]), [4., 5.]);
+}
+
+#[test]
+fn test_viewport_fit() {
+    let world = MBR::new(0., 0., 100., 50.);
+    let lb = Viewport::new(world, 200., 200., Fit::Letterbox);
+    assert_eq!(lb.world, MBR::new(0., -25., 100., 75.));
+    assert_eq!(lb.resolution(), [0.5, 0.5]);
+    let crop = Viewport::new(world, 200., 200., Fit::Crop);
+    assert_eq!(crop.world, MBR::new(25., 0., 75., 50.));
+    let tall = Viewport::new(world, 100., 400., Fit::Letterbox);
+    assert_eq!(tall.world, MBR::new(0., -175., 100., 225.));
+    let stretch = Viewport::new(world, 200., 200., Fit::Stretch);
+    assert_eq!(stretch.world, world);
+    assert_eq!(stretch.resolution(), [0.5, 0.25]);
+    let point = Viewport::new(MBR::new_from_pt([1., 1.]), 10., 10., Fit::Letterbox);
+    assert_eq!(point.world, MBR::new_from_pt([1., 1.]));
+    let line = Viewport::new(MBR::new(0., 0., 10., 0.), 10., 5., Fit::Letterbox);
+    assert_eq!(line.world, MBR::new(0., -2.5, 10., 2.5));
+    for &fit in [Fit::Crop, Fit::Stretch].iter() {
+        let line = Viewport::new(MBR::new(0., 0., 10., 0.), 10., 5., fit);
+        assert_eq!(line.world, MBR::new(0., 0., 10., 0.));
+    }
+
+    //1 m per pixel at 96 dpi
+    let vp = Viewport::new(MBR::new(0., 0., 960., 960.), 960., 960., Fit::Letterbox);
+    assert!((vp.scale_denominator(96., 1.) - 3779.527559).abs() < 1e-6);
+}
+
+#[test]
+fn test_viewport_mapping() {
+    let vp = Viewport::new(MBR::new(10., 20., 30., 40.), 200., 200., Fit::Letterbox);
+    assert_eq!(vp.to_screen([10., 40.]), [0., 0.]);
+    assert_eq!(vp.to_screen([30., 20.]), [200., 200.]);
+    assert_eq!(vp.to_world([100., 50.]), [20., 35.]);
+    assert_eq!(vp.to_world(vp.to_screen([12., 33.])), [12., 33.]);
+    assert_eq!(vp.screen_mbr(&MBR::new(10., 30., 20., 40.)), MBR::new(0., 0., 100., 100.));
+    assert_eq!(vp.world_mbr(&MBR::new(0., 0., 100., 100.)), MBR::new(10., 30., 20., 40.));
+    assert_eq!(vp.transform().inverse().unwrap(), vp.inverse_transform());
+
+    let z = vp.zoom(2., [15., 25.]);
+    assert_eq!(z.world, MBR::new(12.5, 22.5, 22.5, 32.5));
+    assert_eq!(z.to_screen([15., 25.])[0] / 200., (15. - 12.5) / 10.);
+    assert_eq!(vp.zoom(0.5, vp.world.centre()).world, MBR::new(0., 10., 40., 50.));
+
+    assert_eq!(vp.pan(5., -5.).world, MBR::new(15., 15., 35., 35.));
+    //drag right and down by 10px moves the world left and up by 1 unit
+    assert_eq!(vp.pan_pixels(10., 10.).world, MBR::new(9., 21., 29., 41.));
+}